@@ -1,786 +1,6818 @@
 #![no_std]
 
+use core::cmp::Ordering;
+use core::fmt;
+
 const MAX_PLACES: usize = 815;
 const MAX_DEC_PLACES: usize = 39;
 
 #[allow(non_camel_case_types)]
 type decimalsU128 = ([u8; MAX_DEC_PLACES], usize);
-#[allow(non_camel_case_types)]
-type decimalsMax = ([u8; MAX_PLACES], usize);
+
+/// fixed-capacity big number
+///
+/// keeps decimal places in little-endian order, `N` stands for
+/// maximal count of places, thus can be picked per target
+///
+/// with `binary-limbs` feature, computations on more than 39 places borrow
+/// fixed limb buffers of 364 bytes of stack each, at most 5 at once,
+/// computations on fewer places take no extra stack
+#[derive(Clone, Copy)]
+pub struct BigDec<const N: usize = MAX_PLACES>([u8; N], usize);
+
+/// failures reported by `try_` functions
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// result does not fit into available places or type
+    Overflow,
+    /// subtrahend is greater than minuend
+    Underflow,
+    /// divisor is zero
+    DivisionByZero,
+    /// place is not within `0..=9`
+    InvalidDigit,
+    /// no places at all
+    EmptyInput,
+    /// number has no modular inverse
+    NotInvertible,
+}
+
+// checks whether places are valid decimal places
+fn places_ck(num: &[u8]) -> Result<(), Error> {
+    if num.is_empty() {
+        return Err(Error::EmptyInput);
+    }
+
+    if num.iter().any(|&p| p > 9) {
+        return Err(Error::InvalidDigit);
+    }
+
+    Ok(())
+}
 
 // xₙ₊₁ = ½(xₙ+S÷xₙ)
 // X₀ any positive initial estimation
 // lim(x→∞)xₙ = √S
-pub fn herons_sqrt(num: u16) -> u16 {
-    if num == 1 || num == 0 {
-        return num;
-    }
+//
+// 1 < a ≤ b < num, num = a×b = √num×√num
+//  ⇒ a=b=√num ∨ a < b ⇒ a < √num ∧ b > √num
+macro_rules! uint_fns {
+    ($sqrt:ident, $prime_ck:ident, $t:ty) => {
+        uint_fns!(@sqrt $sqrt, $t);
+
+        #[doc = concat!("checks whether `", stringify!($t), "` is prime")]
+        pub fn $prime_ck(num: $t) -> bool {
+            if num < 2 {
+                return false;
+            } else if num == 2 {
+                return true;
+            } else if num & 1 != 1 {
+                return false;
+            }
 
-    let mut cur = num >> 1;
+            let sqrt = $sqrt(num);
 
-    loop {
-        let nex = (cur + num / cur) >> 1;
+            for i in (3..=sqrt).step_by(2) {
+                if num % i == 0 {
+                    return false;
+                }
+            }
 
-        if nex >= cur {
-            break;
+            true
         }
+    };
+    // trial division up to √n is impractical for wide types
+    ($sqrt:ident, $prime_ck:ident, $t:ty, $miller_rabin:ident, $mr_t:ty) => {
+        uint_fns!(@sqrt $sqrt, $t);
+
+        #[doc = concat!("checks whether `", stringify!($t), "` is prime, see `", stringify!($miller_rabin), "`")]
+        pub fn $prime_ck(num: $t) -> bool {
+            $miller_rabin(num as $mr_t)
+        }
+    };
+    (@sqrt $sqrt:ident, $t:ty) => {
+        #[doc = concat!("computes integer square root of `", stringify!($t), "`")]
+        pub fn $sqrt(num: $t) -> $t {
+            if num == 1 || num == 0 {
+                return num;
+            }
 
-        cur = nex;
+            // X₀ = 2^⌈bits/2⌉ ≥ √S thus sequence decreases
+            let bits = <$t>::BITS - num.leading_zeros();
+            let mut cur: $t = 1 << ((bits + 1) >> 1);
+
+            loop {
+                let quot = num / cur;
+                // ½(xₙ+S÷xₙ) without overflow of addition
+                let nex = (cur >> 1) + (quot >> 1) + (cur & quot & 1);
+
+                if nex >= cur {
+                    break;
+                }
+
+                cur = nex;
+            }
+
+            cur
+        }
+    };
+}
+
+uint_fns!(herons_sqrt_u8, prime_ck_u8, u8);
+uint_fns!(herons_sqrt, prime_ck, u16);
+uint_fns!(herons_sqrt_u32, prime_ck_u32, u32);
+uint_fns!(herons_sqrt_u64, prime_ck_u64, u64, miller_rabin_u64, u64);
+uint_fns!(
+    herons_sqrt_u128,
+    prime_ck_u128,
+    u128,
+    miller_rabin_u128,
+    u128
+);
+uint_fns!(
+    herons_sqrt_usize,
+    prime_ck_usize,
+    usize,
+    miller_rabin_u64,
+    u64
+);
+
+// n -1 = d×2ˢ, d odd
+// n prime ⇒ aᵈ ≡ 1 (mod n) ∨ a^(d×2ʳ) ≡ -1 (mod n) for some 0 ≤ r < s
+//
+// first 12 primes as witnesses make test deterministic for
+// n < 318665857834031151167461 ≈ 3.18×10²³, least strong pseudoprime to all of them
+const MR_WITNESSES: [u8; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+macro_rules! miller_rabin_fns {
+    ($small_ck:ident, $strong_ck:ident, $mul_mod:ident, $t:ty) => {
+        // checks whether `num` is strong probable prime to `MR_WITNESSES`
+        fn $strong_ck(num: $t) -> bool {
+            let num_min1 = num - 1;
+            let s = num_min1.trailing_zeros();
+            let d = num_min1 >> s;
+
+            'witness: for w in MR_WITNESSES {
+                let mut x = 1;
+                let mut sqr = w as $t;
+                let mut exp = d;
+
+                while exp > 0 {
+                    if exp & 1 == 1 {
+                        x = $mul_mod(x, sqr, num);
+                    }
+
+                    sqr = $mul_mod(sqr, sqr, num);
+                    exp >>= 1;
+                }
+
+                if x == 1 || x == num_min1 {
+                    continue;
+                }
+
+                for _ in 1..s {
+                    x = $mul_mod(x, x, num);
+
+                    if x == num_min1 {
+                        continue 'witness;
+                    }
+                }
+
+                return false;
+            }
+
+            true
+        }
+
+        // decides `num` by division and strong test, `None` stands for probable prime
+        fn $small_ck(num: $t) -> Option<bool> {
+            if num < 2 {
+                return Some(false);
+            }
+
+            for w in MR_WITNESSES {
+                let w = w as $t;
+                if num % w == 0 {
+                    return Some(num == w);
+                }
+            }
+
+            if !$strong_ck(num) {
+                return Some(false);
+            }
+
+            None
+        }
+    };
+}
+
+miller_rabin_fns!(small_ck_u64, strong_ck_u64, mul_mod_u64, u64);
+miller_rabin_fns!(small_ck_u128, strong_ck_u128, mul_mod_u128, u128);
+
+/// checks whether `u64` is prime using Miller–Rabin test
+///
+/// test is deterministic for whole `u64` range
+pub fn miller_rabin_u64(num: u64) -> bool {
+    small_ck_u64(num).unwrap_or(true)
+}
+
+/// checks whether `u128` is prime using Miller–Rabin test
+///
+/// test is deterministic for `num` < 3.18×10²³. Numbers beyond `u64` pass also
+/// strong Lucas test, which forms Baillie–PSW test, no composite passing it is known.
+pub fn miller_rabin_u128(num: u128) -> bool {
+    if let Ok(num) = u64::try_from(num) {
+        return miller_rabin_u64(num);
     }
 
-    cur
+    small_ck_u128(num).unwrap_or_else(|| lucas_ck_u128(num))
 }
 
-// 1 < a ≤ b < num, num = a×b = √num×√num
-//  ⇒ a=b=√num ∨ a < b ⇒ a < √num ∧ b > √num
-pub fn prime_ck(num: u16) -> bool {
-    if num < 2 {
+// Selfridge parameters: D first of 5, -7, 9, -11, … with Jacobi symbol
+// (D/n) = -1, P = 1, Q = (1 -D)/4
+//
+// n +1 = d×2ˢ, d odd
+// n prime ⇒ U(d) ≡ 0 (mod n) ∨ V(d×2ʳ) ≡ 0 (mod n) for some 0 ≤ r < s
+//
+// U(2k) = U(k)×V(k), V(2k) = V(k)² -2Qᵏ
+// U(k+1) = (P×U(k) +V(k))÷2, V(k+1) = (D×U(k) +P×V(k))÷2
+fn lucas_ck_u128(num: u128) -> bool {
+    // square has no D with (D/n) = -1
+    let sqrt = herons_sqrt_u128(num);
+    if sqrt * sqrt == num {
         return false;
-    } else if num == 2 {
+    }
+
+    let mut d_abs = 5;
+    let mut d_neg = false;
+
+    // `num` is odd and greater than |D|
+    let d = loop {
+        let d = if d_neg { num - d_abs } else { d_abs };
+
+        match jacobi_u128(d, num) {
+            -1 => break d,
+            0 => return false,
+            _ => {}
+        }
+
+        d_abs += 2;
+        d_neg = !d_neg;
+    };
+
+    let q = if d_neg {
+        (d_abs + 1) / 4
+    } else {
+        num - (d_abs - 1) / 4
+    };
+
+    // `num` < 2¹²⁸ -1 since 3 divides 2¹²⁸ -1
+    let num_pl1 = num + 1;
+    let s = num_pl1.trailing_zeros();
+    let exp = num_pl1 >> s;
+
+    let mut u = 1;
+    let mut v = 1;
+    let mut q_k = q;
+
+    for bit in (0..u128::BITS - 1 - exp.leading_zeros()).rev() {
+        u = mul_mod_u128(u, v, num);
+        v = sub_mod_u128(mul_mod_u128(v, v, num), add_mod_u128(q_k, q_k, num), num);
+        q_k = mul_mod_u128(q_k, q_k, num);
+
+        if (exp >> bit) & 1 == 1 {
+            let u_nex = half_mod_u128(add_mod_u128(u, v, num), num);
+            v = half_mod_u128(add_mod_u128(mul_mod_u128(d, u, num), v, num), num);
+            u = u_nex;
+            q_k = mul_mod_u128(q_k, q, num);
+        }
+    }
+
+    if u == 0 || v == 0 {
         return true;
-    } else if num & 1 != 1 {
-        return false;
     }
 
-    let sqrt = herons_sqrt(num);
+    for _ in 1..s {
+        v = sub_mod_u128(mul_mod_u128(v, v, num), add_mod_u128(q_k, q_k, num), num);
 
-    for i in 2u16..=sqrt {
-        if num % i == 0 {
-            return false;
+        if v == 0 {
+            return true;
         }
+
+        q_k = mul_mod_u128(q_k, q_k, num);
     }
 
-    true
+    false
 }
 
-pub trait AsSlice {
-    fn as_slice(&self) -> &[u8];
+// Jacobi symbol (a/n), n odd
+//
+// (2/n) = -1 ⇔ n ≡ 3, 5 (mod 8)
+// (a/n) = (n/a) unless a ≡ n ≡ 3 (mod 4), then (a/n) = -(n/a)
+fn jacobi_u128(mut a: u128, mut n: u128) -> i8 {
+    let mut sym = 1;
+    a %= n;
+
+    while a != 0 {
+        let tz = a.trailing_zeros();
+        a >>= tz;
+
+        if tz & 1 == 1 && matches!(n & 7, 3 | 5) {
+            sym = -sym;
+        }
+
+        if a & 3 == 3 && n & 3 == 3 {
+            sym = -sym;
+        }
+
+        (a, n) = (n % a, a);
+    }
+
+    if n == 1 {
+        sym
+    } else {
+        0
+    }
 }
 
-pub trait AsSliceMut {
-    fn as_slice_mut(&mut self) -> &mut [u8];
+fn mul_mod_u64(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
 }
 
-impl AsSlice for decimalsU128 {
-    fn as_slice(&self) -> &[u8] {
-        &self.0[..self.1]
+// a×b = a×Σbᵢ2ⁱ, doubling and adding modulo `m` avoids overflow
+fn mul_mod_u128(mut a: u128, mut b: u128, m: u128) -> u128 {
+    let mut prod = 0;
+
+    while b > 0 {
+        if b & 1 == 1 {
+            prod = add_mod_u128(prod, a, m);
+        }
+
+        a = add_mod_u128(a, a, m);
+        b >>= 1;
     }
+
+    prod
 }
 
-impl AsSlice for ([u8; MAX_PLACES], usize) {
-    fn as_slice(&self) -> &[u8] {
-        &self.0[..self.1]
+// a, b < m
+fn add_mod_u128(a: u128, b: u128, m: u128) -> u128 {
+    let compl = m - b;
+
+    if a >= compl {
+        a - compl
+    } else {
+        a + b
     }
 }
 
-impl AsSliceMut for decimalsU128 {
-    fn as_slice_mut(&mut self) -> &mut [u8] {
-        &mut self.0[..self.1]
+// a, b < m
+fn sub_mod_u128(a: u128, b: u128, m: u128) -> u128 {
+    if a >= b {
+        a - b
+    } else {
+        a + (m - b)
     }
 }
 
-/// converts number to decimal places
-pub fn to_decimals(mut num: u128) -> decimalsU128 {
-    let mut decimals = [0; MAX_DEC_PLACES];
-    let mut ix = 0;
-    loop {
-        let d = num % 10;
-        decimals[ix] = d as u8;
-        num = num / 10;
+// a < m, m odd
+// a odd ⇒ (a +m)÷2 = ⌊a÷2⌋ +⌊m÷2⌋ +1
+fn half_mod_u128(a: u128, m: u128) -> u128 {
+    if a & 1 == 0 {
+        a >> 1
+    } else {
+        (a >> 1) + (m >> 1) + 1
+    }
+}
 
-        ix += 1;
-        if num == 0 {
-            break;
-        }
+/// checks whether decimal places are prime using probabilistic Miller–Rabin test
+///
+/// `rounds` witnesses from [2, `num` -2] are derived from `seed` by pseudo-random
+/// generator. Composite passes single round for at most ¼ of witnesses, so for
+/// independent uniform witnesses it would pass with probability at most 4⁻ʳᵒᵘⁿᵈˢ.
+///
+/// returns `None` when places are invalid, `rounds` is zero or `N` cannot
+/// hold twice `num` places
+pub fn miller_rabin_big<const N: usize>(num: &[u8], rounds: usize, mut seed: u64) -> Option<bool> {
+    places_ck(num).ok()?;
+
+    if rounds == 0 {
+        return None;
     }
 
-    (decimals, ix)
-}
+    let num = &num[..len_trim(num)];
 
-/// converts decimal places to number
-pub fn from_decimals(decimals: &[u8]) -> u128 {
-    #[cfg(test)]
-    assert!(decimals.len() > 0);
+    let len = num.len();
+    if len * 2 > N {
+        return None;
+    }
+
+    if len == 1 {
+        return Some(prime_ck_u8(num[0]));
+    }
+
+    if num[0] & 1 == 0 {
+        return Some(false);
+    }
+
+    // odd `num` has no borrow
+    let mut num_min1 = BigDec::<N>::from_places(num)?;
+    num_min1.0[0] -= 1;
+
+    // `num` ≥ 11 so witness range is not empty
+    let mut num_min3 = num_min1;
+    num_min3.1 = sub_assign(&mut num_min3.0, num_min3.1, &[2])?;
 
-    let mut num = 0;
-    let len = decimals.len();
+    let mut d = num_min1;
+    let mut s = 0;
 
-    for ix in 0..len {
-        let place = decimals[ix];
-        if place == 0 {
+    while d.0[0] & 1 == 0 {
+        halve(d.as_slice_mut());
+        d.1 = len_trim(d.as_slice());
+        s += 1;
+    }
+
+    let mut w = [0; N];
+    let mut aux = [0; N];
+
+    'witness: for _ in 0..rounds {
+        // twice `num` places reduced by `num` -3 are close to uniform
+        for place in w[..len * 2].iter_mut() {
+            *place = (splitmix64(&mut seed) % 10) as u8;
+        }
+
+        let w_len = rem_sel(&mut w[..len * 2], num_min3.as_slice());
+        let w_len = add_assign(&mut w, w_len, &[2])?;
+
+        let mut x = pow_mod::<N>(&w[..w_len], d.as_slice(), num)?;
+
+        if x.as_slice() == [1] || x.as_slice() == num_min1.as_slice() {
             continue;
         }
 
-        num += place as u128 * 10u128.pow(ix as u32);
+        for _ in 1..s {
+            let x_len = mul_rem(x.as_slice(), x.as_slice(), num, &mut aux)?;
+            x.0[..x_len].copy_from_slice(&aux[..x_len]);
+            x.1 = x_len;
+
+            if x.as_slice() == num_min1.as_slice() {
+                continue 'witness;
+            }
+        }
+
+        return Some(false);
     }
 
-    num
+    Some(true)
 }
 
-// in order to avoid excessive looping rem computation can be speed up
-// by simple substracting 10 multiplications of divisor first
-pub fn rem(dividend: &mut [u8], divisor: &[u8]) -> u128 {
-    // widen divisor
-    let mut wdsor = [0; MAX_PLACES];
+// pseudo-random generator, see https://prng.di.unimi.it/splitmix64.c
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
 
-    let mut end_len = dividend.len();
-    let sor_len = divisor.len();
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
 
-    let sor_hg_ix = sor_len - 1;
+    z ^ (z >> 31)
+}
 
-    // can run in vain when `end_len` == `sor_len` +1 and
-    // divisor cannot be broaden up
-    while end_len > sor_len {
-        let mut wr_ix = end_len - 1;
+// gcd(a, b) = gcd(b, a mod b), gcd(a, 0) = a
+// lcm(a, b) = a ÷ gcd(a, b) × b
+//
+// extended Euclid keeps rᵢ = a×sᵢ + b×tᵢ where rᵢ₊₁ = rᵢ₋₁ - qᵢ×rᵢ,
+// sᵢ₊₁ = sᵢ₋₁ - qᵢ×sᵢ, tᵢ₊₁ = tᵢ₋₁ - qᵢ×tᵢ, signs of sᵢ and tᵢ alternate
+// so only magnitudes |sᵢ₊₁| = |sᵢ₋₁| + qᵢ×|sᵢ| are kept, when last nonzero
+// rₖ has odd k, a×(|sₖ₊₁| - |sₖ|) - b×(|tₖ₊₁| - |tₖ|) = rₖ is used instead
+// since |sₖ₊₁| = b ÷ gcd and |tₖ₊₁| = a ÷ gcd
+macro_rules! gcd_fns {
+    ($gcd:ident, $lcm:ident, $gcd_ext:ident, $mod_inv:ident, $t:ty) => {
+        #[doc = concat!("greatest common divisor of `", stringify!($t), "`")]
+        pub fn $gcd(mut a: $t, mut b: $t) -> $t {
+            while b != 0 {
+                (a, b) = (b, a % b);
+            }
 
-        let mut l_ix = wr_ix;
-        let mut r_ix = sor_hg_ix;
+            a
+        }
 
-        loop {
-            let end_num = dividend[l_ix];
-            let sor_num = divisor[r_ix];
+        #[doc = concat!("least common multiple of `", stringify!($t), "`")]
+        ///
+        /// returns `None` on overflow
+        pub fn $lcm(a: $t, b: $t) -> Option<$t> {
+            if a == 0 || b == 0 {
+                return Some(0);
+            }
 
-            // check whether divisor can be broaded up to
-            // dividend highest place
-            if end_num < sor_num {
-                wr_ix -= 1;
-                break;
-            } else if end_num > sor_num {
-                break;
+            (a / $gcd(a, b)).checked_mul(b)
+        }
+
+        #[doc = concat!("extended Euclid's algorithm over `", stringify!($t), "`")]
+        ///
+        /// returns `(gcd, x, y)` such that `a×x - b×y = gcd`, or `None` for `a = 0`
+        /// and `b ≠ 0` since such nonnegative coefficients do not exist then
+        pub fn $gcd_ext(a: $t, b: $t) -> Option<($t, $t, $t)> {
+            if a == 0 {
+                return if b == 0 { Some((0, 0, 0)) } else { None };
             }
 
-            if r_ix == 0 {
-                break;
+            let (mut r0, mut r1) = (a, b);
+            let (mut s0, mut s1) = (1, 0);
+            let (mut t0, mut t1) = (0, 1);
+            let mut even = true;
+
+            while r1 != 0 {
+                let q = r0 / r1;
+
+                (r0, r1) = (r1, r0 % r1);
+                (s0, s1) = (s1, s0 + q * s1);
+                (t0, t1) = (t1, t0 + q * t1);
+
+                even = !even;
             }
 
-            l_ix -= 1;
-            r_ix -= 1;
+            Some(if even {
+                (r0, s0, t0)
+            } else {
+                (r0, s1 - s0, t1 - t0)
+            })
         }
 
-        let wdsor_len = wr_ix + 1;
-        let mut sor_ix = sor_hg_ix;
-
-        loop {
-            wdsor[wr_ix] = divisor[sor_ix];
+        #[doc = concat!("modular multiplicative inverse over `", stringify!($t), "`")]
+        ///
+        /// returns `x` such that `a×x ≡ 1 (mod m)` or `None` when
+        /// gcd(a, m) ≠ 1 or `m = 0`
+        pub fn $mod_inv(a: $t, m: $t) -> Option<$t> {
+            if m == 0 {
+                return None;
+            }
 
-            if sor_ix == 0 {
-                break;
+            // any number is congruent to 0 modulo 1
+            if m == 1 {
+                return Some(0);
             }
 
-            sor_ix -= 1;
-            wr_ix -= 1;
+            match $gcd_ext(a % m, m)? {
+                (1, x, _) => Some(x % m),
+                _ => None,
+            }
         }
+    };
+}
 
-        end_len = rem_crux(dividend, &wdsor, end_len, wdsor_len);
+gcd_fns!(gcd_u8, lcm_u8, gcd_ext_u8, mod_inv_u8, u8);
+gcd_fns!(gcd_u16, lcm_u16, gcd_ext_u16, mod_inv_u16, u16);
+gcd_fns!(gcd_u32, lcm_u32, gcd_ext_u32, mod_inv_u32, u32);
+gcd_fns!(gcd_u64, lcm_u64, gcd_ext_u64, mod_inv_u64, u64);
+gcd_fns!(gcd_u128, lcm_u128, gcd_ext_u128, mod_inv_u128, u128);
+gcd_fns!(gcd_usize, lcm_usize, gcd_ext_usize, mod_inv_usize, usize);
+
+/// greatest common divisor of decimal places
+///
+/// returns `None` when `a` or `b` does not fit into `N` places
+pub fn gcd<const N: usize>(a: &[u8], b: &[u8]) -> Option<BigDec<N>> {
+    let mut a = BigDec::<N>::from_places(a)?;
+    let mut b = BigDec::<N>::from_places(b)?;
+
+    while !is_zero(b.as_slice()) {
+        a.1 = rem_sel(a.as_slice_mut(), b.as_slice());
+        (a, b) = (b, a);
     }
 
-    // when dividend is already rem this runs "in vain"
-    if end_len == sor_len {
-        end_len = rem_crux(dividend, divisor, end_len, sor_len);
+    Some(a)
+}
+
+/// least common multiple of decimal places
+///
+/// returns `None` when `a`, `b` or multiple does not fit into `N` places
+pub fn lcm<const N: usize>(a: &[u8], b: &[u8]) -> Option<BigDec<N>> {
+    if is_zero(a) || is_zero(b) {
+        return BigDec::from_places(&[0]);
     }
 
-    from_decimals(&dividend[..end_len])
-}
+    let gcd = gcd::<N>(a, b)?;
+    let (quot, _) = div_rem::<N>(a, gcd.as_slice())?;
 
-#[cfg(test)]
-static mut LOOP_COUNTER: usize = 0;
-fn rem_crux(end: &mut [u8], sor: &[u8], end_len: usize, sor_len: usize) -> usize {
-    let mut takeover;
-    let mut ix;
+    mul(quot.as_slice(), b)
+}
 
-    loop {
-        takeover = 0;
-        ix = 0;
+/// extended Euclid's algorithm over decimal places
+///
+/// returns `(gcd, x, y)` such that `a×x - b×y = gcd`, see `gcd_ext_u64`,
+/// or `None` when some of them does not fit into `N` places
+pub fn gcd_ext<const N: usize>(a: &[u8], b: &[u8]) -> Option<(BigDec<N>, BigDec<N>, BigDec<N>)> {
+    let zero = BigDec::<N>::from_places(&[0])?;
+    let one = BigDec::<N>::from_places(&[1])?;
+
+    if is_zero(a) {
+        return if is_zero(b) {
+            Some((zero, zero, zero))
+        } else {
+            None
+        };
+    }
 
-        while ix < end_len {
-            let sor_num = if ix < sor_len {
-                sor[ix]
-            } else if takeover == 0 {
-                break;
-            } else {
-                0
-            };
+    let (mut r0, mut r1) = (BigDec::<N>::from_places(a)?, BigDec::<N>::from_places(b)?);
+    let (mut s0, mut s1) = (one, zero);
+    let (mut t0, mut t1) = (zero, one);
+    let mut even = true;
 
-            let mut end_num = end[ix];
-            let total = sor_num + takeover;
+    while !is_zero(r1.as_slice()) {
+        let (q, r2) = div_rem::<N>(r0.as_slice(), r1.as_slice())?;
 
-            takeover = if end_num < total {
-                end_num += 10;
-                1
-            } else {
-                0
-            };
+        let qs = mul::<N>(q.as_slice(), s1.as_slice())?;
+        let s2 = add::<N>(s0.as_slice(), qs.as_slice())?;
 
-            end[ix] = end_num - total;
-            ix += 1;
-        }
+        let qt = mul::<N>(q.as_slice(), t1.as_slice())?;
+        let t2 = add::<N>(t0.as_slice(), qt.as_slice())?;
 
-        #[cfg(test)]
-        unsafe {
-            LOOP_COUNTER += 1;
-        }
+        (r0, r1) = (r1, r2);
+        (s0, s1) = (s1, s2);
+        (t0, t1) = (t1, t2);
 
-        // add `|| ix < sor_len` to support all longer divisors, see longer_divisor_test1
-        if takeover == 1 {
-            ix = 0;
-            takeover = 0;
+        even = !even;
+    }
 
-            let mut not_len = 0;
+    if even {
+        return Some((r0, s0, t0));
+    }
 
-            while ix < sor_len && ix < end_len {
-                let correction = end[ix] + sor[ix];
+    let x = sub(s1.as_slice(), s0.as_slice())?;
+    let y = sub(t1.as_slice(), t0.as_slice())?;
 
-                let one = ones(correction, &mut takeover);
-                end[ix] = one;
+    Some((r0, x, y))
+}
 
-                if one == 0 {
-                    not_len += 1;
-                } else {
-                    not_len = 0;
-                }
+/// modular multiplicative inverse over decimal places
+///
+/// returns `x` such that `a×x ≡ 1 (mod m)` or `None` when gcd(a, m) ≠ 1,
+/// `m` is zero or `a` or `m` does not fit into `N` places
+pub fn mod_inv<const N: usize>(a: &[u8], m: &[u8]) -> Option<BigDec<N>> {
+    if is_zero(m) {
+        return None;
+    }
 
-                ix += 1;
-            }
+    let mut a = BigDec::<N>::from_places(a)?;
+    a.1 = rem_sel(a.as_slice_mut(), m);
 
-            return if not_len == ix { 1 } else { ix - not_len };
-        }
+    // any number is congruent to 0 modulo 1
+    if m[..len_trim(m)] == [1] {
+        return BigDec::from_places(&[0]);
     }
-}
 
-pub fn pow(base: &[u8], pow: u8) -> decimalsMax {
-    let pow_type = 1;
+    let (gcd, mut x, _) = gcd_ext::<N>(a.as_slice(), m)?;
 
-    match pow_type {
-        1 => pow_log(base, pow),
-        2 => pow_linear(base, pow),
-        _ => panic!(),
+    if gcd.as_slice() != [1] {
+        return None;
     }
+
+    x.1 = rem_sel(x.as_slice_mut(), m);
+    Some(x)
 }
 
-fn pow_log(base: &[u8], pow: u8) -> decimalsMax {
-    let mut aux1 = [0; MAX_PLACES];
+/// modular multiplicative inverse over decimal places
+///
+/// same as `mod_inv` but reports failure reason
+pub fn try_mod_inv<const N: usize>(a: &[u8], m: &[u8]) -> Result<BigDec<N>, Error> {
+    places_ck(a)?;
+    places_ck(m)?;
 
-    if pow == 0 {
-        aux1[0] = 1;
-        return (aux1, 1);
+    if is_zero(m) {
+        return Err(Error::DivisionByZero);
     }
 
-    let base_len = base.len();
-    for ix in 0..base_len {
-        aux1[ix] = base[ix]
+    if len_trim(a) > N || len_trim(m) > N {
+        return Err(Error::Overflow);
     }
 
-    if pow == 1 {
-        return (aux1, base_len);
-    }
+    mod_inv(a, m).ok_or(Error::NotInvertible)
+}
 
-    let mut aux2 = [0; MAX_PLACES];
+// product of first 15 primes is 614889782588491410,
+// thus `u64` has at most 15 distinct prime factors
+const MAX_FACTORS_U64: usize = 15;
+const TRIAL_LIMIT: u64 = 1000;
+
+/// prime factorization of `u64`
+///
+/// returns `(prime, exponent)` pairs in ascending order and their count,
+/// 0 and 1 have no factors. Factors below 1000 are found by trial division,
+/// larger ones by Pollard's rho.
+#[allow(clippy::manual_is_multiple_of)]
+pub fn factorize_u64(mut num: u64) -> ([(u64, u8); MAX_FACTORS_U64], usize) {
+    let mut facs = [(0, 0); MAX_FACTORS_U64];
+    let mut len = 0;
 
-    let mut steps = [0; 7];
-    let mut wr_ix = 0;
-    let mut step = pow;
+    if num < 2 {
+        return (facs, len);
+    }
 
-    loop {
-        steps[wr_ix] = step;
-        step >>= 1;
+    let mut d = 2;
+    while d < TRIAL_LIMIT && d * d <= num {
+        if num % d == 0 {
+            let mut exp = 0;
 
-        // `pow = 1` and `pow = 0` solved above
-        if step == 1 {
-            break;
+            while num % d == 0 {
+                num /= d;
+                exp += 1;
+            }
+
+            facs[len] = (d, exp);
+            len += 1;
         }
 
-        wr_ix += 1;
+        d += if d == 2 { 1 } else { 2 };
     }
 
-    let mut mcand = &mut aux1;
-    let mut sum = &mut aux2;
-
-    let mut mcand_len = base_len;
-    let mut sum_len = 0;
+    if num == 1 {
+        return (facs, len);
+    }
 
-    let mut ixes = (0..=wr_ix).rev();
+    // each split adds at most one composite
+    let mut pending = [0; 64];
+    let mut pen_len = 1;
+    pending[0] = num;
 
-    loop {
-        let re_ix = ixes.next().unwrap();
+    while pen_len > 0 {
+        pen_len -= 1;
+        let num = pending[pen_len];
 
-        for off in 0..mcand_len {
-            sum_len = muladd(&mcand[0..mcand_len], mcand[off], sum, off);
+        if d * d > num || miller_rabin_u64(num) {
+            factor_push(&mut facs, &mut len, num);
+            continue;
         }
 
-        if steps[re_ix] & 1 == 1 {
-            clear_swap(&mut mcand, mcand_len, &mut sum);
-            mcand_len = sum_len;
+        let div = pollards_rho(num);
 
-            for off in 0..base_len {
-                sum_len = muladd(&mcand[0..mcand_len], base[off], sum, off);
-            }
-        }
+        pending[pen_len] = div;
+        pending[pen_len + 1] = num / div;
+        pen_len += 2;
+    }
 
-        if re_ix == 0 {
-            return (*sum, sum_len);
+    facs[..len].sort_unstable_by_key(|f| f.0);
+    (facs, len)
+}
+
+fn factor_push(facs: &mut [(u64, u8)], len: &mut usize, prime: u64) {
+    for fac in facs[..*len].iter_mut() {
+        if fac.0 == prime {
+            fac.1 += 1;
+            return;
         }
+    }
 
-        clear_swap(&mut mcand, mcand_len, &mut sum);
-        mcand_len = sum_len;
+    facs[*len] = (prime, 1);
+    *len += 1;
+}
+
+// xₙ₊₁ = xₙ² + c (mod n), sequence modulo unknown factor p cycles
+// sooner than modulo n, cycle is found by Floyd's tortoise and hare
+// and gcd(|x - y|, n) reveals p
+//
+// `num` has to be odd composite
+fn pollards_rho(num: u64) -> u64 {
+    let mut c = 1;
+
+    loop {
+        let next = |x| ((x as u128 * x as u128 + c) % num as u128) as u64;
+
+        let mut x = 2;
+        let mut y = 2;
+        let mut d = 1;
+
+        while d == 1 {
+            x = next(x);
+            y = next(next(y));
+
+            d = gcd_u64(x.abs_diff(y), num);
+        }
+
+        if d != num {
+            return d;
+        }
+
+        c += 1;
     }
 }
 
-fn clear_swap<'a>(
-    mcand: &mut &'a mut [u8; MAX_PLACES],
-    mcand_len: usize,
-    sum: *mut &'a mut [u8; MAX_PLACES],
-) {
-    for ix in 0..mcand_len {
-        mcand[ix] = 0;
+macro_rules! factorize_fns {
+    ($factorize:ident, $t:ty, $max_facs:expr) => {
+        #[doc = concat!("prime factorization of `", stringify!($t), "`, see `factorize_u64`")]
+        pub fn $factorize(num: $t) -> ([($t, u8); $max_facs], usize) {
+            let (facs_u64, len) = factorize_u64(num as u64);
+
+            let mut facs = [(0, 0); $max_facs];
+            for ix in 0..len {
+                let (prime, exp) = facs_u64[ix];
+                facs[ix] = (prime as $t, exp);
+            }
+
+            (facs, len)
+        }
+    };
+}
+
+// 2×3×5×7×11×13×17 > u16::MAX
+factorize_fns!(factorize_u16, u16, 6);
+// 2×3×5×7×11×13×17×19×23×29 > u32::MAX
+factorize_fns!(factorize_u32, u32, 9);
+
+/// sieve of Eratosthenes over bit-packed segment
+///
+/// marks primes within `low..low + 8 × bitmap.len()`, bit `i` of byte `i ÷ 8`
+/// (least significant first) is set when `low + i` is prime, bits beyond
+/// `u64::MAX` are cleared
+///
+/// sieving primes are not kept anywhere, thus segment beyond `low` is crossed
+/// out by every odd number up to its square root
+pub fn sieve_segment(bitmap: &mut [u8], low: u64) {
+    sieve_segment_by(bitmap, low, &[]);
+}
+
+// crosses out segment by ascending primes of `base` first and then by every
+// odd number beyond them, up to square root of segment end
+fn sieve_segment_by(bitmap: &mut [u8], low: u64, base: &[u16]) {
+    if bitmap.is_empty() {
+        return;
+    }
+
+    bitmap.fill(u8::MAX);
+
+    let bits = bitmap.len() * 8;
+    let last = match low.checked_add(bits as u64 - 1) {
+        Some(last) => last,
+        None => {
+            for ix in (u64::MAX - low) as usize + 1..bits {
+                bit_clear(bitmap, ix);
+            }
+
+            u64::MAX
+        }
+    };
+
+    for num in low..=last.min(1) {
+        bit_clear(bitmap, (num - low) as usize);
+    }
+
+    let mut p = 2;
+
+    for &prime in base {
+        if !cross_out(bitmap, low, last, prime as u64) {
+            return;
+        }
+
+        p = prime as u64 + if prime == 2 { 1 } else { 2 };
+    }
+
+    while cross_out(bitmap, low, last, p) {
+        p += if p == 2 { 1 } else { 2 };
+    }
+}
+
+// crosses out multiples of `p` within `low..=last` from p² on
+//
+// returns `false` when p² is beyond `last`, sieving is done then
+fn cross_out(bitmap: &mut [u8], low: u64, last: u64, p: u64) -> bool {
+    let sqr = match p.checked_mul(p) {
+        Some(sqr) if sqr <= last => sqr,
+        _ => return false,
+    };
+
+    // composite `p` within segment is already crossed out by its factors
+    if p >= low && !bit_ck(bitmap, (p - low) as usize) {
+        return true;
+    }
+
+    let mut mul = if sqr >= low {
+        sqr
+    } else {
+        match low.div_ceil(p).checked_mul(p) {
+            Some(mul) => mul,
+            None => return true,
+        }
+    };
+
+    while mul <= last {
+        bit_clear(bitmap, (mul - low) as usize);
+
+        mul = match mul.checked_add(p) {
+            Some(mul) => mul,
+            None => break,
+        };
+    }
+
+    true
+}
+
+/// sieve of Eratosthenes over bit-packed buffer
+///
+/// marks primes within `0..=limit`, bits above `limit` are cleared,
+/// see `sieve_segment`
+///
+/// returns count of primes or `None` when `bitmap` cannot hold `limit + 1` bits
+pub fn sieve(bitmap: &mut [u8], limit: usize) -> Option<usize> {
+    let len = limit / 8 + 1;
+    if len > bitmap.len() {
+        return None;
+    }
+
+    let bitmap = &mut bitmap[..len];
+    sieve_segment(bitmap, 0);
+
+    for ix in limit + 1..len * 8 {
+        bit_clear(bitmap, ix);
+    }
+
+    Some(bitmap.iter().map(|b| b.count_ones() as usize).sum())
+}
+
+/// fills `list` with primes up to `limit` inclusive
+///
+/// returns count of primes written, stops when `list` is full
+pub fn primes_into(list: &mut [u16], limit: u16) -> usize {
+    let mut count = 0;
+
+    for (p, wr) in primes_up_to(limit as u64).zip(list.iter_mut()) {
+        *wr = p as u16;
+        count += 1;
+    }
+
+    count
+}
+
+/// iterates primes up to `limit` inclusive, see `Primes`
+pub fn primes_up_to(limit: u64) -> Primes {
+    let mut seg = [0; PRIMES_SEG_LEN];
+    sieve_segment(&mut seg, 0);
+
+    Primes {
+        seg,
+        base: [0; PRIMES_BASE_LEN],
+        base_len: 0,
+        low: 0,
+        ix: 0,
+        limit,
+    }
+}
+
+const PRIMES_SEG_LEN: usize = 64;
+
+// 1024th prime is 8161, thus kept primes sieve segments up to 8161²
+const PRIMES_BASE_LEN: usize = 1024;
+
+/// prime iterator backed by `sieve_segment` over fixed segment
+///
+/// first 1024 primes are kept once iterated and sieve later segments,
+/// segments beyond 8161² are crossed out by odd numbers past them too
+pub struct Primes {
+    seg: [u8; PRIMES_SEG_LEN],
+    base: [u16; PRIMES_BASE_LEN],
+    base_len: usize,
+    low: u64,
+    ix: usize,
+    limit: u64,
+}
+
+impl Iterator for Primes {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        const SEG_BITS: usize = PRIMES_SEG_LEN * 8;
+
+        loop {
+            // iteration stops cleanly at `u64::MAX`
+            let num = match self.low.checked_add(self.ix as u64) {
+                Some(num) if num <= self.limit => num,
+                _ => return None,
+            };
+
+            if self.ix == SEG_BITS {
+                self.low = num;
+                self.ix = 0;
+
+                sieve_segment_by(&mut self.seg, self.low, &self.base[..self.base_len]);
+                continue;
+            }
+
+            self.ix += 1;
+
+            if bit_ck(&self.seg, self.ix - 1) {
+                if self.base_len < PRIMES_BASE_LEN {
+                    self.base[self.base_len] = num as u16;
+                    self.base_len += 1;
+                }
+
+                return Some(num);
+            }
+        }
+    }
+}
+
+fn bit_ck(bitmap: &[u8], ix: usize) -> bool {
+    bitmap[ix >> 3] & (1 << (ix & 7)) != 0
+}
+
+fn bit_clear(bitmap: &mut [u8], ix: usize) {
+    bitmap[ix >> 3] &= !(1 << (ix & 7));
+}
+
+/// computes integer square root of decimal places
+///
+/// returns floor of square root and remainder, that is `num - root²`,
+/// or `None` when `num` does not fit into `N` places
+pub fn big_sqrt<const N: usize>(num: &[u8]) -> Option<(BigDec<N>, BigDec<N>)> {
+    let num = &num[..len_trim(num)];
+    let len = num.len();
+
+    if len > N {
+        return None;
+    }
+
+    // fits into `u16`
+    if len < 5 {
+        let num = from_decimals(num) as u16;
+        let root = herons_sqrt(num);
+        let rem = num - root * root;
+
+        let root = BigDec::from_places(to_decimals(root as u128).as_slice())?;
+        let rem = BigDec::from_places(to_decimals(rem as u128).as_slice())?;
+        return Some((root, rem));
+    }
+
+    // X₀ = 10^⌈len/2⌉ ≥ √S thus sequence decreases
+    let mut cur = [0; N];
+    cur[len.div_ceil(2)] = 1;
+    let mut cur = BigDec(cur, len.div_ceil(2) + 1);
+
+    loop {
+        let (quot, _) = div_rem::<N>(num, cur.as_slice())?;
+
+        let mut nex = add::<N>(cur.as_slice(), quot.as_slice())?;
+        halve(nex.as_slice_mut());
+        nex.1 = len_trim(nex.as_slice());
+
+        if cmp(nex.as_slice(), cur.as_slice()) != Ordering::Less {
+            break;
+        }
+
+        cur = nex;
+    }
+
+    let sqr = mul::<N>(cur.as_slice(), cur.as_slice())?;
+    let rem = sub::<N>(num, sqr.as_slice())?;
+
+    Some((cur, rem))
+}
+
+pub trait AsSlice {
+    fn as_slice(&self) -> &[u8];
+}
+
+pub trait AsSliceMut {
+    fn as_slice_mut(&mut self) -> &mut [u8];
+}
+
+impl AsSlice for decimalsU128 {
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..self.1]
+    }
+}
+
+impl<const N: usize> BigDec<N> {
+    /// creates big number from decimal places
+    ///
+    /// leading zeros are dropped, returns `None` when places
+    /// do not fit into `N` places
+    pub fn from_places(places: &[u8]) -> Option<Self> {
+        let len = len_trim(places);
+        if len > N || N == 0 {
+            return None;
+        }
+
+        let mut dec = [0; N];
+        dec[..len].copy_from_slice(&places[..len]);
+
+        Some(BigDec(dec, if len == 0 { 1 } else { len }))
+    }
+}
+
+impl<const N: usize> AsSlice for BigDec<N> {
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..self.1]
+    }
+}
+
+impl AsSliceMut for decimalsU128 {
+    fn as_slice_mut(&mut self) -> &mut [u8] {
+        &mut self.0[..self.1]
+    }
+}
+
+impl<const N: usize> AsSliceMut for BigDec<N> {
+    fn as_slice_mut(&mut self) -> &mut [u8] {
+        &mut self.0[..self.1]
+    }
+}
+
+// keeps `Place` and `Radix` implementations within this crate
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// storage of single place
+///
+/// sealed, implemented only for `u8`, `u16` and `u32`
+pub trait Place: sealed::Sealed + Copy + Ord + Default {
+    /// widens place for intermediate computation
+    fn wide(self) -> u64;
+    /// narrows value lower than radix into place
+    fn narrow(wide: u64) -> Self;
+}
+
+macro_rules! place_impl {
+    ($t:ty) => {
+        impl sealed::Sealed for $t {}
+
+        impl Place for $t {
+            fn wide(self) -> u64 {
+                self as u64
+            }
+
+            fn narrow(wide: u64) -> Self {
+                wide as $t
+            }
+        }
+    };
+}
+
+place_impl!(u8);
+place_impl!(u16);
+place_impl!(u32);
+
+/// radix of place arithmetic
+///
+/// sealed, implemented only for `Dec`, `Dec2`, `Dec4`, `Bin8` and `Bin32`,
+/// product of two places plus `RADIX` has to fit into `u64`
+pub trait Radix: sealed::Sealed {
+    /// storage of single place
+    type Place: Place;
+    /// count of distinct place values
+    const RADIX: u64;
+}
+
+macro_rules! radix_impl {
+    ($name:ident, $place:ty, $radix:expr, $doc:literal) => {
+        #[doc = $doc]
+        pub struct $name;
+
+        impl sealed::Sealed for $name {}
+
+        impl Radix for $name {
+            type Place = $place;
+            const RADIX: u64 = $radix;
+        }
+    };
+}
+
+radix_impl!(Dec, u8, 10, "radix 10, one decimal digit per place");
+radix_impl!(Dec2, u8, 100, "radix 100, two decimal digits per place");
+radix_impl!(
+    Dec4,
+    u16,
+    10_000,
+    "radix 10⁴, four decimal digits per place"
+);
+radix_impl!(Bin8, u8, 1 << 8, "radix 2⁸, one byte per place");
+radix_impl!(Bin32, u32, 1 << 32, "radix 2³², one 32-bit word per place");
+
+/// fixed-capacity signed big number
+///
+/// keeps sign and magnitude, magnitude uses same layout as `BigDec`,
+/// zero is never negative
+#[derive(Clone, Copy)]
+pub struct SignedBigDec<const N: usize = MAX_PLACES> {
+    neg: bool,
+    mag: BigDec<N>,
+}
+
+impl<const N: usize> SignedBigDec<N> {
+    /// creates signed big number from sign and decimal places
+    ///
+    /// returns `None` when places do not fit into `N` places
+    pub fn from_places(neg: bool, places: &[u8]) -> Option<Self> {
+        Some(signed(neg, BigDec::from_places(places)?))
+    }
+
+    /// creates signed big number from sign and magnitude
+    pub fn from_mag(neg: bool, mag: BigDec<N>) -> Self {
+        signed(neg, mag)
+    }
+
+    /// tells whether number is less than zero
+    pub fn is_neg(&self) -> bool {
+        self.neg
+    }
+
+    /// provides magnitude, that is absolute value
+    pub fn mag(&self) -> &BigDec<N> {
+        &self.mag
+    }
+
+    /// provides number with opposite sign
+    pub fn opposite(&self) -> Self {
+        signed(!self.neg, self.mag)
+    }
+}
+
+impl<const N: usize> AsSlice for SignedBigDec<N> {
+    fn as_slice(&self) -> &[u8] {
+        self.mag.as_slice()
+    }
+}
+
+// keeps zero non-negative
+fn signed<const N: usize>(neg: bool, mag: BigDec<N>) -> SignedBigDec<N> {
+    let neg = neg && !is_zero(mag.as_slice());
+    SignedBigDec { neg, mag }
+}
+
+/// computes sum of signed big numbers
+///
+/// returns `None` when sum does not fit into `N` places
+pub fn add_signed<const N: usize>(
+    augend: &SignedBigDec<N>,
+    addend: &SignedBigDec<N>,
+) -> Option<SignedBigDec<N>> {
+    let (aug_mag, add_mag) = (augend.as_slice(), addend.as_slice());
+
+    if augend.neg == addend.neg {
+        return Some(signed(augend.neg, add::<N>(aug_mag, add_mag)?));
+    }
+
+    // |a| ≥ |b| ⇒ a + b = sgn(a)(|a| - |b|)
+    match cmp(aug_mag, add_mag) {
+        Ordering::Less => Some(signed(addend.neg, sub::<N>(add_mag, aug_mag)?)),
+        _ => Some(signed(augend.neg, sub::<N>(aug_mag, add_mag)?)),
+    }
+}
+
+/// computes difference of signed big numbers
+///
+/// returns `None` when difference does not fit into `N` places
+pub fn sub_signed<const N: usize>(
+    minuend: &SignedBigDec<N>,
+    subtrahend: &SignedBigDec<N>,
+) -> Option<SignedBigDec<N>> {
+    add_signed(minuend, &subtrahend.opposite())
+}
+
+/// computes product of signed big numbers
+///
+/// returns `None` when product does not fit into `N` places
+pub fn mul_signed<const N: usize>(
+    mcand: &SignedBigDec<N>,
+    mpler: &SignedBigDec<N>,
+) -> Option<SignedBigDec<N>> {
+    let prod = mul::<N>(mcand.as_slice(), mpler.as_slice())?;
+    Some(signed(mcand.neg != mpler.neg, prod))
+}
+
+/// computes quotient and remainder of signed big numbers
+///
+/// quotient is truncated toward zero, thus remainder takes sign
+/// of `dividend`, returns `None` when `divisor` is zero
+pub fn div_rem_trunc<const N: usize>(
+    dividend: &SignedBigDec<N>,
+    divisor: &SignedBigDec<N>,
+) -> Option<(SignedBigDec<N>, SignedBigDec<N>)> {
+    let (quot, rem) = div_rem::<N>(dividend.as_slice(), divisor.as_slice())?;
+
+    Some((
+        signed(dividend.neg != divisor.neg, quot),
+        signed(dividend.neg, rem),
+    ))
+}
+
+/// computes quotient and remainder of signed big numbers
+///
+/// quotient is rounded toward negative infinity, thus remainder
+/// takes sign of `divisor`, returns `None` when `divisor` is zero
+/// or when quotient does not fit into `N` places
+pub fn div_rem_floor<const N: usize>(
+    dividend: &SignedBigDec<N>,
+    divisor: &SignedBigDec<N>,
+) -> Option<(SignedBigDec<N>, SignedBigDec<N>)> {
+    let (quot, rem) = div_rem::<N>(dividend.as_slice(), divisor.as_slice())?;
+
+    if dividend.neg == divisor.neg || is_zero(rem.as_slice()) {
+        return Some((
+            signed(dividend.neg != divisor.neg, quot),
+            signed(dividend.neg, rem),
+        ));
+    }
+
+    // a = bq + r, 0 < |r| < |b| ⇒ a = b(q - 1) + (r + b)
+    let quot = add::<N>(quot.as_slice(), &[1])?;
+    let rem = sub::<N>(divisor.as_slice(), rem.as_slice())?;
+
+    Some((signed(true, quot), signed(divisor.neg, rem)))
+}
+
+/// converts number to decimal places
+pub fn to_decimals(mut num: u128) -> decimalsU128 {
+    let mut decimals = [0; MAX_DEC_PLACES];
+    let mut ix = 0;
+    loop {
+        let d = num % 10;
+        decimals[ix] = d as u8;
+        num /= 10;
+
+        ix += 1;
+        if num == 0 {
+            break;
+        }
+    }
+
+    (decimals, ix)
+}
+
+/// converts decimal places to number
+///
+/// value exceeding `u128` wraps around, see `try_` variant
+#[allow(clippy::needless_range_loop)]
+pub fn from_decimals(decimals: &[u8]) -> u128 {
+    #[cfg(test)]
+    assert!(!decimals.is_empty());
+
+    // Horner's scheme, aₙ10ⁿ + … + a₀ = (…(aₙ×10 + aₙ₋₁)×10 + …)×10 + a₀
+    let mut num: u128 = 0;
+    for ix in (0..decimals.len()).rev() {
+        num = num.wrapping_mul(10).wrapping_add(decimals[ix] as u128);
+    }
+
+    num
+}
+
+/// converts decimal places to number
+///
+/// same as `from_decimals` but reports invalid places and overflow
+pub fn try_from_decimals(decimals: &[u8]) -> Result<u128, Error> {
+    places_ck(decimals)?;
+
+    let mut num: u128 = 0;
+    for ix in (0..decimals.len()).rev() {
+        num = match num
+            .checked_mul(10)
+            .and_then(|n| n.checked_add(decimals[ix] as u128))
+        {
+            Some(n) => n,
+            None => return Err(Error::Overflow),
+        };
     }
 
-    let swap: *mut [u8; MAX_PLACES] = *mcand;
+    Ok(num)
+}
+
+macro_rules! udec_fns {
+    ($to:ident, $from:ident, $try_from:ident, $t:ty) => {
+        #[doc = concat!("converts `", stringify!($t), "` to decimal places")]
+        pub fn $to(num: $t) -> BigDec<{ <$t>::MAX.ilog10() as usize + 1 }> {
+            let (dec, len) = to_decimals(num as u128);
+
+            let mut places = [0; { <$t>::MAX.ilog10() as usize + 1 }];
+            places[..len].copy_from_slice(&dec[..len]);
+
+            BigDec(places, len)
+        }
+
+        #[doc = concat!("converts decimal places to `", stringify!($t), "`")]
+        ///
+        /// value exceeding type is truncated, see `try_` variant
+        pub fn $from(decimals: &[u8]) -> $t {
+            from_decimals(decimals) as $t
+        }
+
+        #[doc = concat!("converts decimal places to `", stringify!($t), "`")]
+        ///
+        #[doc = concat!("same as `", stringify!($from), "` but reports invalid places and overflow")]
+        pub fn $try_from(decimals: &[u8]) -> Result<$t, Error> {
+            let num = try_from_decimals(decimals)?;
+            <$t>::try_from(num).map_err(|_| Error::Overflow)
+        }
+    };
+}
+
+udec_fns!(to_decimals_u8, from_decimals_u8, try_from_decimals_u8, u8);
+udec_fns!(
+    to_decimals_u16,
+    from_decimals_u16,
+    try_from_decimals_u16,
+    u16
+);
+udec_fns!(
+    to_decimals_u32,
+    from_decimals_u32,
+    try_from_decimals_u32,
+    u32
+);
+udec_fns!(
+    to_decimals_u64,
+    from_decimals_u64,
+    try_from_decimals_u64,
+    u64
+);
+udec_fns!(
+    to_decimals_usize,
+    from_decimals_usize,
+    try_from_decimals_usize,
+    usize
+);
+
+macro_rules! idec_fns {
+    ($to:ident, $from:ident, $try_from:ident, $t:ty) => {
+        #[doc = concat!("converts `", stringify!($t), "` to sign and decimal places")]
+        pub fn $to(num: $t) -> SignedBigDec<{ <$t>::MAX.ilog10() as usize + 1 }> {
+            let (dec, len) = to_decimals(num.unsigned_abs() as u128);
+
+            let mut places = [0; { <$t>::MAX.ilog10() as usize + 1 }];
+            places[..len].copy_from_slice(&dec[..len]);
+
+            signed(num < 0, BigDec(places, len))
+        }
+
+        #[doc = concat!("converts sign and decimal places to `", stringify!($t), "`")]
+        ///
+        /// value exceeding type is truncated, see `try_` variant
+        pub fn $from<const N: usize>(num: &SignedBigDec<N>) -> $t {
+            let mag = from_decimals(num.as_slice());
+            let num = if num.neg { mag.wrapping_neg() } else { mag };
+
+            num as $t
+        }
+
+        #[doc = concat!("converts sign and decimal places to `", stringify!($t), "`")]
+        ///
+        #[doc = concat!("same as `", stringify!($from), "` but reports invalid places and overflow")]
+        pub fn $try_from<const N: usize>(num: &SignedBigDec<N>) -> Result<$t, Error> {
+            let mag = try_from_decimals(num.as_slice())?;
+
+            // |MIN| = MAX + 1
+            if num.neg {
+                if mag > <$t>::MIN.unsigned_abs() as u128 {
+                    return Err(Error::Overflow);
+                }
+
+                Ok((mag as $t).wrapping_neg())
+            } else {
+                <$t>::try_from(mag).map_err(|_| Error::Overflow)
+            }
+        }
+    };
+}
+
+idec_fns!(to_decimals_i8, from_decimals_i8, try_from_decimals_i8, i8);
+idec_fns!(
+    to_decimals_i16,
+    from_decimals_i16,
+    try_from_decimals_i16,
+    i16
+);
+idec_fns!(
+    to_decimals_i32,
+    from_decimals_i32,
+    try_from_decimals_i32,
+    i32
+);
+idec_fns!(
+    to_decimals_i64,
+    from_decimals_i64,
+    try_from_decimals_i64,
+    i64
+);
+idec_fns!(
+    to_decimals_i128,
+    from_decimals_i128,
+    try_from_decimals_i128,
+    i128
+);
+idec_fns!(
+    to_decimals_isize,
+    from_decimals_isize,
+    try_from_decimals_isize,
+    isize
+);
+
+/// failures reported by `parse_` functions
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// no digits at all
+    Empty,
+    /// byte at index is neither digit nor underscore, or sign is misplaced
+    InvalidChar(usize),
+    /// minus sign for unsigned number
+    Negative,
+    /// number does not fit into available places
+    Overflow,
+    /// radix is not within `2..=36`
+    InvalidRadix,
+}
+
+/// parses ASCII decimal string into big number
+///
+/// accepts optional `+` sign, underscores following first digit,
+/// and leading zeros, `-0` is zero thus accepted too
+pub fn parse_decimal<const N: usize>(src: impl AsRef<[u8]>) -> Result<BigDec<N>, ParseError> {
+    let num = parse_signed_decimal::<N>(src)?;
+    if num.neg {
+        return Err(ParseError::Negative);
+    }
+
+    Ok(num.mag)
+}
+
+/// parses ASCII decimal string into signed big number
+///
+/// accepts optional `+` or `-` sign, underscores following first digit,
+/// and leading zeros
+pub fn parse_signed_decimal<const N: usize>(
+    src: impl AsRef<[u8]>,
+) -> Result<SignedBigDec<N>, ParseError> {
+    let src = src.as_ref();
+
+    let start = match src.first() {
+        Some(b'+') | Some(b'-') => 1,
+        Some(_) => 0,
+        None => return Err(ParseError::Empty),
+    };
+
+    let neg = start == 1 && src[0] == b'-';
+    let len = src.len();
+
+    if start == len {
+        return Err(ParseError::Empty);
+    }
+
+    for (ix, &c) in src.iter().enumerate().skip(start) {
+        match c {
+            b'0'..=b'9' => {}
+            b'_' if ix > start => {}
+            _ => return Err(ParseError::InvalidChar(ix)),
+        }
+    }
+
+    let mut places = [0; N];
+    let mut wr_ix = 0;
+
+    // most significant digit goes last
+    for ix in (start..len).rev() {
+        let c = src[ix];
+        if c == b'_' {
+            continue;
+        }
+
+        let d = c - b'0';
+        if wr_ix < N {
+            places[wr_ix] = d;
+        } else if d != 0 {
+            return Err(ParseError::Overflow);
+        }
+
+        wr_ix += 1;
+    }
+
+    if N == 0 {
+        return Err(ParseError::Overflow);
+    }
+
+    let len = len_trim(&places[..wr_ix.min(N)]);
+    Ok(signed(neg, BigDec(places, len)))
+}
+
+/// renders decimal places into buffer as ASCII, most significant first
+///
+/// `sep` is put between each three places counting from least significant
+/// one, it should be ASCII, returns `None` when buffer is too short, place
+/// is not within `0..=9`, or `sep` is not ASCII
+pub fn write_decimal<'a>(num: &[u8], buf: &'a mut [u8], sep: Option<u8>) -> Option<&'a str> {
+    let len = len_trim(num);
+    let num = if len == 0 { &[0][..] } else { &num[..len] };
+    let len = num.len();
+
+    let out_len = match sep {
+        Some(_) => len + (len - 1) / 3,
+        None => len,
+    };
+
+    if buf.len() < out_len {
+        return None;
+    }
+
+    let mut wr_ix = out_len;
+    for (ix, &place) in num.iter().enumerate() {
+        if place > 9 {
+            return None;
+        }
+
+        if let Some(sep) = sep {
+            if ix > 0 && ix % 3 == 0 {
+                wr_ix -= 1;
+                buf[wr_ix] = sep;
+            }
+        }
+
+        wr_ix -= 1;
+        buf[wr_ix] = b'0' + place;
+    }
+
+    core::str::from_utf8(&buf[..out_len]).ok()
+}
+
+// renders places most significant first, place beyond `0..=9` as `?`
+//
+// `buf` has to hold `num` places
+fn display_places<'a>(num: &[u8], buf: &'a mut [u8]) -> &'a str {
+    let len = num.len();
+
+    for (wr, &place) in buf[..len].iter_mut().rev().zip(num) {
+        *wr = if place < 10 { b'0' + place } else { b'?' };
+    }
+
+    core::str::from_utf8(&buf[..len]).unwrap_or_default()
+}
+
+impl<const N: usize> fmt::Display for BigDec<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = [0; N];
+        f.pad_integral(true, "", display_places(self.as_slice(), &mut buf))
+    }
+}
+
+impl<const N: usize> fmt::Debug for BigDec<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BigDec")
+            .field("places", &self.as_slice())
+            .field("len", &self.1)
+            .finish()
+    }
+}
+
+impl<const N: usize> fmt::Display for SignedBigDec<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = [0; N];
+        f.pad_integral(!self.neg, "", display_places(self.as_slice(), &mut buf))
+    }
+}
+
+impl<const N: usize> fmt::Debug for SignedBigDec<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SignedBigDec")
+            .field("neg", &self.neg)
+            .field("mag", &self.mag)
+            .finish()
+    }
+}
+
+// multiplies little-endian digits of `radix` by `mpler` and adds `addend`,
+// returns new length or `None` when result does not fit into `acc`
+fn mul_add_small<P: Place>(
+    acc: &mut [P],
+    mut len: usize,
+    mpler: u64,
+    addend: u64,
+    radix: u64,
+) -> Option<usize> {
+    let mut carry = addend;
+
+    for place in acc[..len].iter_mut() {
+        let cur = place.wide() * mpler + carry;
+
+        *place = P::narrow(cur % radix);
+        carry = cur / radix;
+    }
+
+    while carry > 0 {
+        if len == acc.len() {
+            return None;
+        }
+
+        acc[len] = P::narrow(carry % radix);
+        carry /= radix;
+        len += 1;
+    }
+
+    Some(len)
+}
+
+// converts digits of `radix`, most significant first, to digits of `acc_radix`
+// by Horner's scheme, digits are left little-endian in `acc`, their count
+// is returned
+fn horner<P: Place>(
+    digits: impl Iterator<Item = u64>,
+    radix: u64,
+    acc: &mut [P],
+    acc_radix: u64,
+) -> Option<usize> {
+    if acc.is_empty() {
+        return None;
+    }
+
+    acc[0] = P::default();
+    let mut len = 1;
+
+    for d in digits {
+        len = mul_add_small(acc, len, radix, d, acc_radix)?;
+    }
+
+    Some(len)
+}
+
+// converts decimal places to digits of `radix`, digits are left
+// little-endian in `acc`, their count is returned
+fn radix_digits<P: Place>(num: &[u8], acc: &mut [P], radix: u64) -> Option<usize> {
+    let num = &num[..len_trim(num)];
+    if num.iter().any(|&p| p > 9) {
+        return None;
+    }
+
+    horner(num.iter().rev().map(|&p| p as u64), 10, acc, radix)
+}
+
+// converts digits of `radix`, most significant first, to decimal places
+fn from_radix_digits<const N: usize>(
+    digits: impl Iterator<Item = u64>,
+    radix: u64,
+) -> Option<BigDec<N>> {
+    let mut places = [0; N];
+    let len = horner(digits, radix, &mut places, 10)?;
+
+    Some(BigDec(places, len))
+}
+
+/// converts decimal places to places of radix `R`
+///
+/// places are left in `places` buffer, returns their count or `None`
+/// when buffer is too short or place is not within `0..=9`
+pub fn to_radix_places<R: Radix>(num: &[u8], places: &mut [R::Place]) -> Option<usize> {
+    radix_digits(num, places, R::RADIX)
+}
+
+/// converts places of radix `R` to decimal places
+///
+/// decimal places are left in `num` buffer, returns their count or `None`
+/// when buffer is too short or place is not lower than radix
+pub fn from_radix_places<R: Radix>(places: &[R::Place], num: &mut [u8]) -> Option<usize> {
+    let places = &places[..len_trim(places)];
+    if places.iter().any(|p| p.wide() >= R::RADIX) {
+        return None;
+    }
+
+    horner(places.iter().rev().map(|p| p.wide()), R::RADIX, num, 10)
+}
+
+/// converts decimal places to `u32` limbs of radix 2³²
+///
+/// limbs are left little-endian in `limbs` buffer, returns their count
+/// or `None` when buffer is too short or place is not within `0..=9`
+pub fn to_limbs(num: &[u8], limbs: &mut [u32]) -> Option<usize> {
+    to_radix_places::<Bin32>(num, limbs)
+}
+
+/// converts `u32` limbs of radix 2³² to decimal places
+///
+/// decimal places are left in `num` buffer, returns their count
+/// or `None` when buffer is too short
+pub fn from_limbs(limbs: &[u32], num: &mut [u8]) -> Option<usize> {
+    from_radix_places::<Bin32>(limbs, num)
+}
+
+// binary-limb backend, selected by `binary-limbs` feature
+//
+// places are converted into limbs of radix 2³², computed on and converted
+// back. Each limb holds at least 9 places, thus buffers of `MAX_LIMBS` limbs
+// hold `MAX_PLACES` places, capacities beyond stay on decimal places.
+// Buffers are fixed, so places up to `u128` range stay decimal too and limb
+// functions are never inlined, their buffers are not paid by decimal path.
+
+const MAX_LIMBS: usize = MAX_PLACES / 9 + 1;
+const LIMBS_MIN_PLACES: usize = MAX_DEC_PLACES + 1;
+
+// whether computation on `places` places goes to limbs
+fn limbs_sel(places: usize) -> bool {
+    cfg!(feature = "binary-limbs") && (LIMBS_MIN_PLACES..=MAX_PLACES).contains(&places)
+}
+
+fn limbs_to_dec<const N: usize>(limbs: &[u32]) -> Option<BigDec<N>> {
+    let mut places = [0; N];
+    let len = from_limbs(limbs, &mut places)?;
+
+    Some(BigDec(places, len))
+}
+
+#[inline(never)]
+fn mul_limbs<const N: usize>(mcand: &[u8], mpler: &[u8]) -> Option<BigDec<N>> {
+    if N == 0 {
+        return None;
+    }
+
+    if is_zero(mcand) || is_zero(mpler) {
+        return Some(BigDec([0; N], 1));
+    }
+
+    let mut mcand_limbs = [0; MAX_LIMBS];
+    let mcand_len = to_limbs(mcand, &mut mcand_limbs)?;
+
+    let mut mpler_limbs = [0; MAX_LIMBS];
+    let mpler_len = to_limbs(mpler, &mut mpler_limbs)?;
+
+    let mut prod = [0; MAX_LIMBS];
+    let prod_len = mul_into_radix::<Bin32>(
+        &mcand_limbs[..mcand_len],
+        &mpler_limbs[..mpler_len],
+        &mut prod,
+    )?;
+
+    limbs_to_dec(&prod[..prod_len])
+}
+
+#[inline(never)]
+fn div_rem_limbs<const N: usize>(
+    dividend: &[u8],
+    divisor: &[u8],
+) -> Option<(BigDec<N>, BigDec<N>)> {
+    if is_zero(divisor) {
+        return None;
+    }
+
+    let dividend = &dividend[..len_trim(dividend)];
+    let divisor = &divisor[..len_trim(divisor)];
+
+    if dividend.len() > N {
+        return None;
+    }
+
+    // divisor limbs may not fit then
+    if cmp(dividend, divisor) == Ordering::Less {
+        return Some((BigDec([0; N], 1), BigDec::from_places(dividend)?));
+    }
+
+    let mut end = [0; MAX_LIMBS];
+    let end_len = to_limbs(dividend, &mut end)?;
+
+    let mut sor = [0; MAX_LIMBS];
+    let sor_len = to_limbs(divisor, &mut sor)?;
+
+    let mut quot = [0; MAX_LIMBS];
+    let (quot_len, rem_len) =
+        div_rem_radix::<Bin32>(&mut end[..end_len], &sor[..sor_len], &mut quot)?;
+
+    Some((
+        limbs_to_dec(&quot[..quot_len])?,
+        limbs_to_dec(&end[..rem_len])?,
+    ))
+}
+
+#[inline(never)]
+fn pow_limbs<const N: usize>(base: &[u8], pow: u64) -> Option<BigDec<N>> {
+    let base = &base[..len_trim(base)];
+    let base_len = base.len();
+
+    if base_len > N {
+        return None;
+    }
+
+    // limbs capacity is higher than `N` places, fail fast
+    // on decimal places count, see `pow_radix`
+    if base_len > 1 {
+        let min_len = ((base_len - 1) as u64).saturating_mul(pow);
+        if min_len >= N as u64 {
+            return None;
+        }
+    }
+
+    let mut limbs = [0; MAX_LIMBS];
+    let len = to_limbs(base, &mut limbs)?;
+
+    let (pow, pow_len) = pow_radix::<Bin32, MAX_LIMBS>(&limbs[..len], pow)?;
+    limbs_to_dec(&pow[..pow_len])
+}
+
+// remainder of `dividend` is left in place, returns its length or `None`
+// when some of places do not fit into limbs
+#[inline(never)]
+fn rem_limbs(dividend: &mut [u8], divisor: &[u8]) -> Option<usize> {
+    let mut end = [0; MAX_LIMBS];
+    let end_len = to_limbs(dividend, &mut end)?;
+
+    rem_limbs_into(&mut end[..end_len], divisor, dividend)
+}
+
+// product of `mcand` and `mpler` reduced by `modulus` is left in `prod`,
+// returns its length or `None` when some of places do not fit into limbs
+#[inline(never)]
+fn mul_rem_limbs(mcand: &[u8], mpler: &[u8], modulus: &[u8], prod: &mut [u8]) -> Option<usize> {
+    let mut mcand_limbs = [0; MAX_LIMBS];
+    let mcand_len = to_limbs(mcand, &mut mcand_limbs)?;
+
+    let mut mpler_limbs = [0; MAX_LIMBS];
+    let mpler_len = to_limbs(mpler, &mut mpler_limbs)?;
+
+    let mut end = [0; MAX_LIMBS];
+    let end_len = mul_into_radix::<Bin32>(
+        &mcand_limbs[..mcand_len],
+        &mpler_limbs[..mpler_len],
+        &mut end,
+    )?;
+
+    rem_limbs_into(&mut end[..end_len], modulus, prod)
+}
+
+// reduces `end` limbs by `divisor`, remainder is left as decimal places
+// in `rem` buffer with places above it cleared
+fn rem_limbs_into(end: &mut [u32], divisor: &[u8], rem: &mut [u8]) -> Option<usize> {
+    let mut sor = [0; MAX_LIMBS];
+    let sor_len = to_limbs(divisor, &mut sor)?;
+
+    let mut quot = [0; MAX_LIMBS];
+    let (_, rem_len) = div_rem_radix::<Bin32>(end, &sor[..sor_len], &mut quot)?;
+
+    let len = from_limbs(&end[..rem_len], rem)?;
+    rem[len..].fill(0);
+
+    Some(len)
+}
+
+/// converts little-endian bytes to big number
+///
+/// returns `None` when number does not fit into `N` places
+pub fn from_le_bytes<const N: usize>(bytes: &[u8]) -> Option<BigDec<N>> {
+    from_radix_digits(bytes.iter().rev().map(|&b| b as u64), 256)
+}
+
+/// converts big-endian bytes to big number
+///
+/// returns `None` when number does not fit into `N` places
+pub fn from_be_bytes<const N: usize>(bytes: &[u8]) -> Option<BigDec<N>> {
+    from_radix_digits(bytes.iter().map(|&b| b as u64), 256)
+}
+
+/// converts decimal places to little-endian bytes
+///
+/// renders least count of bytes, at least one, returns `None` when buffer
+/// is too short or place is not within `0..=9`
+pub fn to_le_bytes<'a>(num: &[u8], buf: &'a mut [u8]) -> Option<&'a [u8]> {
+    let len = radix_digits(num, buf, 256)?;
+    Some(&buf[..len])
+}
+
+/// converts decimal places to big-endian bytes
+///
+/// renders least count of bytes, at least one, returns `None` when buffer
+/// is too short or place is not within `0..=9`
+pub fn to_be_bytes<'a>(num: &[u8], buf: &'a mut [u8]) -> Option<&'a [u8]> {
+    let len = radix_digits(num, buf, 256)?;
+    buf[..len].reverse();
+
+    Some(&buf[..len])
+}
+
+/// renders decimal places into buffer as ASCII digits of `radix`
+///
+/// most significant digit goes first, letters are lowercase, returns `None`
+/// when buffer is too short, place is not within `0..=9`, or `radix` is not
+/// within `2..=36`
+pub fn write_radix<'a>(num: &[u8], radix: u32, buf: &'a mut [u8]) -> Option<&'a str> {
+    if !(2..=36).contains(&radix) {
+        return None;
+    }
+
+    let len = radix_digits(num, buf, radix as u64)?;
+    buf[..len].reverse();
+
+    for d in buf[..len].iter_mut() {
+        *d = if *d < 10 { b'0' + *d } else { b'a' + *d - 10 };
+    }
+
+    core::str::from_utf8(&buf[..len]).ok()
+}
+
+/// parses ASCII string of `radix` digits into big number
+///
+/// accepts `0x`, `0o`, `0b` prefix matching `radix`, underscores following
+/// first digit, leading zeros, and letters of both cases
+pub fn parse_radix<const N: usize>(
+    src: impl AsRef<[u8]>,
+    radix: u32,
+) -> Result<BigDec<N>, ParseError> {
+    if !(2..=36).contains(&radix) {
+        return Err(ParseError::InvalidRadix);
+    }
+
+    let src = src.as_ref();
+
+    let prefix = match radix {
+        16 => b'x',
+        8 => b'o',
+        2 => b'b',
+        _ => 0,
+    };
+
+    let start = match src {
+        [b'0', p, ..] if prefix != 0 && p.to_ascii_lowercase() == prefix => 2,
+        _ => 0,
+    };
+
+    let len = src.len();
+    if start == len {
+        return Err(ParseError::Empty);
+    }
+
+    for (ix, &c) in src.iter().enumerate().skip(start) {
+        match c {
+            b'_' if ix > start => {}
+            _ if (c as char).is_digit(radix) => {}
+            _ => return Err(ParseError::InvalidChar(ix)),
+        }
+    }
+
+    let digits = src[start..]
+        .iter()
+        .filter_map(|&c| (c as char).to_digit(radix))
+        .map(|d| d as u64);
+
+    from_radix_digits(digits, radix as u64).ok_or(ParseError::Overflow)
+}
+
+/// computes remainder of decimal places
+///
+/// returns `None` when `divisor` is zero, including zero with
+/// leading zeros or no places at all
+pub fn rem(dividend: &mut [u8], divisor: &[u8]) -> Option<u128> {
+    let end_len = rem_big(dividend, divisor)?;
+    Some(from_decimals(&dividend[..end_len]))
+}
+
+/// computes remainder of decimal places
+///
+/// same as `rem` but reports invalid input and remainder not fitting `u128`
+pub fn try_rem(dividend: &mut [u8], divisor: &[u8]) -> Result<u128, Error> {
+    let end_len = try_rem_big(dividend, divisor)?;
+    try_from_decimals(&dividend[..end_len])
+}
+
+/// computes remainder of decimal places in place
+///
+/// remainder is left in `dividend`, places above it are cleared,
+/// returns its length or `None` when `divisor` is zero
+pub fn rem_big(dividend: &mut [u8], divisor: &[u8]) -> Option<usize> {
+    if is_zero(divisor) {
+        return None;
+    }
+
+    Some(rem_sel(dividend, divisor))
+}
+
+/// computes remainder of decimal places in place
+///
+/// same as `rem_big` but reports invalid input
+pub fn try_rem_big(dividend: &mut [u8], divisor: &[u8]) -> Result<usize, Error> {
+    places_ck(dividend)?;
+    places_ck(divisor)?;
+
+    rem_big(dividend, divisor).ok_or(Error::DivisionByZero)
+}
+
+/// computes quotient and remainder of decimal places
+///
+/// returns `None` when `divisor` is zero or when `dividend`
+/// does not fit into `N` places
+pub fn div_rem<const N: usize>(dividend: &[u8], divisor: &[u8]) -> Option<(BigDec<N>, BigDec<N>)> {
+    if limbs_sel(N) {
+        return div_rem_limbs(dividend, divisor);
+    }
+
+    if is_zero(divisor) {
+        return None;
+    }
+
+    let end_len = len_trim(dividend);
+    if end_len > N {
+        return None;
+    }
+
+    // zero has 1 place, even when given no places at all
+    if end_len == 0 {
+        let zero = BigDec::from_places(&[0])?;
+        return Some((zero, zero));
+    }
+
+    let mut rem = [0; N];
+    rem[..end_len].copy_from_slice(&dividend[..end_len]);
+
+    let mut quot = [0; N];
+
+    let rem_len = rem_core::<Dec>(&mut rem[..end_len], divisor, Some(&mut quot));
+    let quot_len = len_trim(&quot[..end_len]);
+
+    Some((BigDec(quot, quot_len), BigDec(rem, rem_len)))
+}
+
+/// computes quotient and remainder of decimal places
+///
+/// same as `div_rem` but reports failure reason
+pub fn try_div_rem<const N: usize>(
+    dividend: &[u8],
+    divisor: &[u8],
+) -> Result<(BigDec<N>, BigDec<N>), Error> {
+    places_ck(dividend)?;
+    places_ck(divisor)?;
+
+    if is_zero(divisor) {
+        return Err(Error::DivisionByZero);
+    }
+
+    div_rem(dividend, divisor).ok_or(Error::Overflow)
+}
+
+/// computes quotient and remainder of places of radix `R` in place
+///
+/// remainder is left in `dividend`, quotient is written into `quot` which
+/// has to hold `dividend` places without leading zeros, returns lengths of
+/// quotient and remainder or `None` when `divisor` is zero or `quot` is short
+pub fn div_rem_radix<R: Radix>(
+    dividend: &mut [R::Place],
+    divisor: &[R::Place],
+    quot: &mut [R::Place],
+) -> Option<(usize, usize)> {
+    if is_zero(divisor) {
+        return None;
+    }
+
+    let end_len = len_trim(dividend);
+    if quot.len() < end_len {
+        return None;
+    }
+
+    let quot = &mut quot[..end_len];
+    quot.fill(R::Place::default());
+
+    let rem_len = rem_core::<R>(&mut dividend[..end_len], divisor, Some(quot));
+    Some((len_trim(quot), rem_len))
+}
+
+// remainder is left in `dividend`, its length is returned, count of
+// subtractions of each widened divisor is added into `quot` place
+//
+// zero `divisor` never produces takeover in `rem_crux` and loops forever,
+// callers have to rule it out
+fn rem_core<R: Radix>(
+    dividend: &mut [R::Place],
+    divisor: &[R::Place],
+    mut quot: Option<&mut [R::Place]>,
+) -> usize {
+    let mut end_len = len_trim(dividend);
+    let sor_len = len_trim(divisor);
+
+    let mut takes = 0;
+
+    let sor_hg_ix = sor_len - 1;
+
+    // can run in vain when `end_len` == `sor_len` +1 and
+    // divisor cannot be broaden up
+    while end_len > sor_len {
+        let mut wr_ix = end_len - 1;
+
+        let mut l_ix = wr_ix;
+        let mut r_ix = sor_hg_ix;
+
+        loop {
+            let end_num = dividend[l_ix];
+            let sor_num = divisor[r_ix];
+
+            // check whether divisor can be broaded up to
+            // dividend highest place
+            if end_num < sor_num {
+                wr_ix -= 1;
+                break;
+            } else if end_num > sor_num {
+                break;
+            }
+
+            if r_ix == 0 {
+                break;
+            }
+
+            l_ix -= 1;
+            r_ix -= 1;
+        }
+
+        // divisor is widen by offset instead of copying it into
+        // auxiliary buffer, places below offset stay untouched
+        let off = wr_ix - sor_hg_ix;
+        let end = &mut dividend[off..];
+        let crux_len = rem_crux::<R>(end, divisor, end_len - off, sor_len, &mut takes);
+
+        if let Some(quot) = quot.as_deref_mut() {
+            quot[off] = R::Place::narrow(quot[off].wide() + takes as u64);
+        }
+
+        end_len = len_trim(&dividend[..off + crux_len]);
+    }
+
+    // when dividend is already rem this runs "in vain"
+    if end_len == sor_len {
+        end_len = rem_crux::<R>(dividend, divisor, end_len, sor_len, &mut takes);
+
+        if let Some(quot) = quot {
+            quot[0] = R::Place::narrow(quot[0].wide() + takes as u64);
+        }
+    }
+
+    end_len
+}
+
+// count of places without leading zeros, zero has 1 place
+fn len_trim<P: Place>(num: &[P]) -> usize {
+    let mut len = num.len();
+
+    while len > 1 {
+        if num[len - 1] != P::default() {
+            break;
+        }
+
+        len -= 1;
+    }
+
+    len
+}
+
+#[cfg(test)]
+static mut LOOP_COUNTER: usize = 0;
+// subtracts `sor` from `end` as many times as possible, count is left
+// in `takes`, `end` is expected to be lower than `sor` × radix
+//
+// each pass subtracts estimated multiple of `sor`, estimate never exceeds
+// real count, thus last pass is always trial one reverted on underflow
+fn rem_crux<R: Radix>(
+    end: &mut [R::Place],
+    sor: &[R::Place],
+    end_len: usize,
+    sor_len: usize,
+    takes: &mut usize,
+) -> usize {
+    let radix = R::RADIX;
+
+    // places of `end` from highest place of `sor` up divided by
+    // highest place of `sor` +1 underestimate quotient
+    let sor_hg = sor[sor_len - 1].wide() + 1;
+
+    let mut takeover;
+    let mut ix;
+
+    *takes = 0;
+
+    loop {
+        let mut end_hg: u64 = 0;
+        for ix in (sor_len - 1..end_len).rev() {
+            end_hg = end_hg.saturating_mul(radix).saturating_add(end[ix].wide());
+        }
+
+        let mpler = (end_hg / sor_hg).clamp(1, radix - 1);
+
+        takeover = 0;
+        ix = 0;
+
+        while ix < end_len {
+            let sor_num = if ix < sor_len {
+                sor[ix].wide() * mpler
+            } else if takeover == 0 {
+                break;
+            } else {
+                0
+            };
+
+            let total = sor_num + takeover;
+            let sub_num = total % radix;
+
+            let mut end_num = end[ix].wide();
+
+            takeover = total / radix;
+            if end_num < sub_num {
+                end_num += radix;
+                takeover += 1;
+            }
+
+            end[ix] = R::Place::narrow(end_num - sub_num);
+            ix += 1;
+        }
+
+        #[cfg(test)]
+        unsafe {
+            LOOP_COUNTER += 1;
+        }
+
+        // add `|| ix < sor_len` to support all longer divisors, see longer_divisor_test1
+        if takeover != 0 {
+            ix = 0;
+            takeover = 0;
+
+            let mut not_len = 0;
+
+            while ix < sor_len && ix < end_len {
+                let correction = end[ix].wide() + sor[ix].wide();
+
+                let one = ones::<R>(correction, &mut takeover);
+                end[ix] = one;
+
+                if one == R::Place::default() {
+                    not_len += 1;
+                } else {
+                    not_len = 0;
+                }
+
+                ix += 1;
+            }
+
+            return if not_len == ix { 1 } else { ix - not_len };
+        }
+
+        *takes += mpler as usize;
+    }
+}
+
+/// computes sum of decimal places
+///
+/// returns `None` when sum does not fit into `N` places
+pub fn add<const N: usize>(augend: &[u8], addend: &[u8]) -> Option<BigDec<N>> {
+    let mut sum = [0; N];
+
+    let aug_len = len_trim(augend);
+    if aug_len > N {
+        return None;
+    }
+
+    sum[..aug_len].copy_from_slice(&augend[..aug_len]);
+
+    let sum_len = add_assign(&mut sum, aug_len, addend)?;
+    Some(BigDec(sum, sum_len))
+}
+
+/// computes sum of decimal places
+///
+/// same as `add` but reports failure reason
+pub fn try_add<const N: usize>(augend: &[u8], addend: &[u8]) -> Result<BigDec<N>, Error> {
+    places_ck(augend)?;
+    places_ck(addend)?;
+
+    add(augend, addend).ok_or(Error::Overflow)
+}
+
+/// adds `addend` into `augend` buffer in place
+///
+/// `augend_len` marks places of `augend` in use, places above are considered
+/// to be free. Returns new length or `None` when sum overflows `augend` buffer,
+/// then buffer content is unspecified.
+pub fn add_assign(augend: &mut [u8], augend_len: usize, addend: &[u8]) -> Option<usize> {
+    add_assign_radix::<Dec>(augend, augend_len, addend)
+}
+
+/// adds `addend` into `augend` buffer of radix `R` places in place
+///
+/// same as `add_assign` but over places of radix `R`
+pub fn add_assign_radix<R: Radix>(
+    augend: &mut [R::Place],
+    augend_len: usize,
+    addend: &[R::Place],
+) -> Option<usize> {
+    let cap = augend.len();
+
+    let aug_len = len_trim(&augend[..augend_len]);
+    let add_len = len_trim(addend);
+
+    let mut sum_len = if aug_len > add_len { aug_len } else { add_len };
+
+    // zero has 1 place, even when given no places at all
+    if sum_len == 0 {
+        sum_len = 1;
+    }
+
+    if sum_len > cap {
+        return None;
+    }
+
+    let mut takeover = 0;
+    for ix in 0..sum_len {
+        let aug_num = if ix < aug_len { augend[ix].wide() } else { 0 };
+        let add_num = if ix < add_len { addend[ix].wide() } else { 0 };
+
+        augend[ix] = ones::<R>(aug_num + add_num, &mut takeover);
+    }
+
+    if takeover == 0 {
+        return Some(sum_len);
+    }
+
+    if sum_len == cap {
+        return None;
+    }
+
+    augend[sum_len] = R::Place::narrow(takeover);
+    Some(sum_len + 1)
+}
+
+/// computes difference of decimal places
+///
+/// returns `None` when `subtrahend` is greater than `minuend` or
+/// when `minuend` does not fit into `N` places
+pub fn sub<const N: usize>(minuend: &[u8], subtrahend: &[u8]) -> Option<BigDec<N>> {
+    let mut diff = [0; N];
+
+    let min_len = len_trim(minuend);
+    if min_len > N {
+        return None;
+    }
+
+    diff[..min_len].copy_from_slice(&minuend[..min_len]);
+
+    let diff_len = sub_assign(&mut diff, min_len, subtrahend)?;
+    Some(BigDec(diff, diff_len))
+}
+
+/// computes difference of decimal places
+///
+/// same as `sub` but reports failure reason
+pub fn try_sub<const N: usize>(minuend: &[u8], subtrahend: &[u8]) -> Result<BigDec<N>, Error> {
+    places_ck(minuend)?;
+    places_ck(subtrahend)?;
+
+    let min_len = len_trim(minuend);
+    let sub_len = len_trim(subtrahend);
+
+    if cmp(&minuend[..min_len], &subtrahend[..sub_len]) == Ordering::Less {
+        return Err(Error::Underflow);
+    }
+
+    sub(minuend, subtrahend).ok_or(Error::Overflow)
+}
+
+/// subtracts `subtrahend` from `minuend` buffer in place
+///
+/// `minuend_len` marks places of `minuend` in use. Returns new length
+/// or `None` when `subtrahend` is greater than `minuend`, then buffer
+/// is left untouched.
+pub fn sub_assign(minuend: &mut [u8], minuend_len: usize, subtrahend: &[u8]) -> Option<usize> {
+    sub_assign_radix::<Dec>(minuend, minuend_len, subtrahend)
+}
+
+/// subtracts `subtrahend` from `minuend` buffer of radix `R` places in place
+///
+/// same as `sub_assign` but over places of radix `R`
+pub fn sub_assign_radix<R: Radix>(
+    minuend: &mut [R::Place],
+    minuend_len: usize,
+    subtrahend: &[R::Place],
+) -> Option<usize> {
+    let mut min_len = len_trim(&minuend[..minuend_len]);
+    let sub_len = len_trim(subtrahend);
+
+    // zero has 1 place, even when given no places at all
+    if min_len == 0 {
+        *minuend.first_mut()? = R::Place::default();
+        min_len = 1;
+    }
+
+    if cmp(&minuend[..min_len], &subtrahend[..sub_len]) == Ordering::Less {
+        return None;
+    }
+
+    let mut takeover = 0;
+    for ix in 0..min_len {
+        let sub_num = if ix < sub_len {
+            subtrahend[ix].wide()
+        } else if takeover == 0 {
+            break;
+        } else {
+            0
+        };
+
+        let mut min_num = minuend[ix].wide();
+        let total = sub_num + takeover;
+
+        takeover = if min_num < total {
+            min_num += R::RADIX;
+            1
+        } else {
+            0
+        };
+
+        minuend[ix] = R::Place::narrow(min_num - total);
+    }
+
+    Some(len_trim(&minuend[..min_len]))
+}
+
+// compares places without leading zeros
+fn cmp<P: Place>(a: &[P], b: &[P]) -> Ordering {
+    let a_len = a.len();
+    let b_len = b.len();
+
+    if a_len != b_len {
+        return a_len.cmp(&b_len);
+    }
+
+    let mut ix = a_len;
+    while ix > 0 {
+        ix -= 1;
+
+        let ord = a[ix].cmp(&b[ix]);
+        if ord != Ordering::Equal {
+            return ord;
+        }
+    }
+
+    Ordering::Equal
+}
+
+/// computes product of decimal places
+///
+/// returns `None` when product does not fit into `N` places
+pub fn mul<const N: usize>(mcand: &[u8], mpler: &[u8]) -> Option<BigDec<N>> {
+    if limbs_sel(N) {
+        return mul_limbs(mcand, mpler);
+    }
+
+    let mut prod = [0; N];
+
+    let prod_len = mul_into(mcand, mpler, &mut prod)?;
+    Some(BigDec(prod, prod_len))
+}
+
+/// computes product of decimal places
+///
+/// same as `mul` but reports failure reason
+pub fn try_mul<const N: usize>(mcand: &[u8], mpler: &[u8]) -> Result<BigDec<N>, Error> {
+    places_ck(mcand)?;
+    places_ck(mpler)?;
+
+    mul(mcand, mpler).ok_or(Error::Overflow)
+}
+
+/// computes product of decimal places into `prod` buffer
+///
+/// returns product length or `None` when product overflows `prod`
+/// buffer, then buffer content is unspecified
+pub fn mul_into(mcand: &[u8], mpler: &[u8], prod: &mut [u8]) -> Option<usize> {
+    mul_into_radix::<Dec>(mcand, mpler, prod)
+}
+
+/// computes product of radix `R` places into `prod` buffer
+///
+/// same as `mul_into` but over places of radix `R`
+pub fn mul_into_radix<R: Radix>(
+    mcand: &[R::Place],
+    mpler: &[R::Place],
+    prod: &mut [R::Place],
+) -> Option<usize> {
+    let cap = prod.len();
+    let zero = R::Place::default();
+
+    if cap == 0 {
+        return None;
+    }
+
+    if is_zero(mcand) || is_zero(mpler) {
+        prod[0] = zero;
+        return Some(1);
+    }
+
+    let mcand_len = len_trim(mcand);
+    let mpler_len = len_trim(mpler);
+
+    // product has at least `mcand_len + mpler_len - 1` places
+    // and at most `mcand_len + mpler_len` places
+    let mut prod_len = mcand_len + mpler_len;
+    if prod_len - 1 > cap {
+        return None;
+    }
+
+    if prod_len > cap {
+        prod_len = cap;
+    }
+
+    prod[..prod_len].fill(zero);
+
+    let mcand = &mcand[..mcand_len];
+    for (off, &mpler_num) in mpler[..mpler_len].iter().enumerate() {
+        if mpler_num == zero {
+            continue;
+        }
+
+        if muladd::<R>(mcand, mpler_num, prod, off) > cap {
+            return None;
+        }
+    }
+
+    Some(len_trim(&prod[..prod_len]))
+}
+
+// zero or no places at all
+fn is_zero<P: Place>(num: &[P]) -> bool {
+    num.iter().all(|&p| p == P::default())
+}
+
+/// computes power of decimal places
+///
+/// panics when power does not fit into default capacity, see `try_pow`
+pub fn pow(base: &[u8], pow: u64) -> BigDec {
+    pow_cap(base, pow)
+}
+
+/// computes power of decimal places
+///
+/// panics when power does not fit into `N` places, see `try_pow`
+pub fn pow_cap<const N: usize>(base: &[u8], pow: u64) -> BigDec<N> {
+    pow_sel(base, pow).expect("power exceeds capacity")
+}
+
+/// computes power of decimal places
+///
+/// same as `pow` but reports failure instead of panicking
+pub fn try_pow<const N: usize>(base: &[u8], pow: u64) -> Result<BigDec<N>, Error> {
+    places_ck(base)?;
+    pow_sel(base, pow).ok_or(Error::Overflow)
+}
+
+/// computes power of decimal places with exponent in decimal places
+///
+/// exponent beyond `u64` is supported only for base 0 and 1,
+/// any other base surely overflows
+pub fn try_pow_big_exp<const N: usize>(base: &[u8], exp: &[u8]) -> Result<BigDec<N>, Error> {
+    places_ck(base)?;
+    places_ck(exp)?;
+
+    let exp = match try_from_decimals(exp) {
+        Ok(e) if e <= u64::MAX as u128 => e as u64,
+        // 0ⁿ = 0, 1ⁿ = 1 for n > 0
+        _ if len_trim(base) == 1 && base[0] < 2 => 1,
+        _ => return Err(Error::Overflow),
+    };
+
+    pow_sel(base, exp).ok_or(Error::Overflow)
+}
+
+fn pow_sel<const N: usize>(base: &[u8], pow: u64) -> Option<BigDec<N>> {
+    if limbs_sel(N) {
+        return pow_limbs(base, pow);
+    }
+
+    pow_radix::<Dec, N>(base, pow).map(|(places, len)| BigDec(places, len))
+}
+
+/// computes power of radix `R` places
+///
+/// returns places and their count or `None` when power does not fit
+/// into `N` places
+pub fn pow_radix<R: Radix, const N: usize>(
+    base: &[R::Place],
+    pow: u64,
+) -> Option<([R::Place; N], usize)> {
+    let base = &base[..len_trim(base)];
+    let base_len = base.len();
+
+    if base_len > N || N == 0 {
+        return None;
+    }
+
+    // power of base having `base_len` places has at least
+    // `(base_len -1) × pow +1` places, fail fast on it
+    if base_len > 1 {
+        let min_len = ((base_len - 1) as u64).saturating_mul(pow);
+        if min_len >= N as u64 {
+            return None;
+        }
+    }
+
+    let pow_type = 1;
+
+    match pow_type {
+        1 => pow_log::<R, N>(base, pow),
+        2 => pow_linear::<R, N>(base, pow),
+        _ => panic!(),
+    }
+}
+
+#[allow(clippy::needless_range_loop)]
+fn pow_log<R: Radix, const N: usize>(
+    base: &[R::Place],
+    pow: u64,
+) -> Option<([R::Place; N], usize)> {
+    let zero = R::Place::default();
+    let mut aux1 = [zero; N];
+
+    if pow == 0 {
+        aux1[0] = R::Place::narrow(1);
+        return Some((aux1, 1));
+    }
+
+    let base_len = base.len();
+    aux1[..base_len].copy_from_slice(base);
+
+    if pow == 1 {
+        return Some((aux1, base_len));
+    }
+
+    let mut aux2 = [zero; N];
+
+    // index of bit just below highest one, highest one stands for
+    // `base` itself, `pow = 1` and `pow = 0` solved above
+    let wr_ix = (u64::BITS - pow.leading_zeros() - 2) as usize;
+
+    let mut mcand = &mut aux1;
+    let mut sum = &mut aux2;
+
+    let mut mcand_len = base_len;
+    let mut sum_len = 0;
+
+    let mut ixes = (0..=wr_ix).rev();
+
+    loop {
+        let re_ix = ixes.next().unwrap();
+
+        for off in 0..mcand_len {
+            sum_len = muladd::<R>(&mcand[0..mcand_len], mcand[off], sum, off);
+
+            if sum_len > N {
+                return None;
+            }
+        }
+
+        if (pow >> re_ix) & 1 == 1 {
+            clear_swap(&mut mcand, mcand_len, &mut sum);
+            mcand_len = sum_len;
+
+            for off in 0..base_len {
+                sum_len = muladd::<R>(&mcand[0..mcand_len], base[off], sum, off);
+
+                if sum_len > N {
+                    return None;
+                }
+            }
+        }
+
+        if re_ix == 0 {
+            return Some((*sum, sum_len));
+        }
+
+        clear_swap(&mut mcand, mcand_len, &mut sum);
+        mcand_len = sum_len;
+    }
+}
+
+/// computes modular power of decimal places
+///
+/// reduces by `modulus` after every squaring and multiplication so
+/// `N` has to hold product of two remainders, that is twice `modulus`
+/// places, `base` and `exp` have to fit into `N` places too
+///
+/// returns `None` when `modulus` is zero or some of above does not fit
+pub fn pow_mod<const N: usize>(base: &[u8], exp: &[u8], modulus: &[u8]) -> Option<BigDec<N>> {
+    if is_zero(modulus) {
+        return None;
+    }
+
+    let mod_len = len_trim(modulus);
+    let base_len = len_trim(base);
+    let mut exp_len = len_trim(exp);
+
+    if mod_len * 2 > N || base_len > N || exp_len > N {
+        return None;
+    }
+
+    let mut sqr = [0; N];
+    sqr[..base_len].copy_from_slice(&base[..base_len]);
+    let mut sqr_len = rem_sel(&mut sqr[..base_len], modulus);
+
+    // 1 mod 1 = 0
+    let mut pow = [0; N];
+    pow[0] = 1;
+    let mut pow_len = rem_sel(&mut pow[..1], modulus);
+
+    let mut exp_aux = [0; N];
+    exp_aux[..exp_len].copy_from_slice(&exp[..exp_len]);
+    let exp = &mut exp_aux;
+
+    let mut aux = [0; N];
+
+    // bⁿ = (b²)ⁿᐟ² for even n, bⁿ = b×(b²)⁽ⁿ⁻¹⁾ᐟ² for odd n
+    while !is_zero(&exp[..exp_len]) {
+        let bit = halve(&mut exp[..exp_len]);
+        exp_len = len_trim(&exp[..exp_len]);
+
+        if bit == 1 {
+            pow_len = mul_rem(&pow[..pow_len], &sqr[..sqr_len], modulus, &mut aux)?;
+            pow[..pow_len].copy_from_slice(&aux[..pow_len]);
+        }
+
+        if is_zero(&exp[..exp_len]) {
+            break;
+        }
+
+        sqr_len = mul_rem(&sqr[..sqr_len], &sqr[..sqr_len], modulus, &mut aux)?;
+        sqr[..sqr_len].copy_from_slice(&aux[..sqr_len]);
+    }
+
+    Some(BigDec(pow, pow_len))
+}
+
+// computes product of `mcand` and `mpler` reduced by `modulus` into `prod`
+fn mul_rem(mcand: &[u8], mpler: &[u8], modulus: &[u8], prod: &mut [u8]) -> Option<usize> {
+    if limbs_sel(mcand.len() + mpler.len()) {
+        if let Some(len) = mul_rem_limbs(mcand, mpler, modulus, prod) {
+            return Some(len);
+        }
+    }
+
+    let prod_len = mul_into(mcand, mpler, prod)?;
+    Some(rem_core::<Dec>(&mut prod[..prod_len], modulus, None))
+}
+
+// remainder of `dividend` is left in place with places above it cleared,
+// its length is returned, `divisor` must not be zero, see `rem_core`
+fn rem_sel(dividend: &mut [u8], divisor: &[u8]) -> usize {
+    if limbs_sel(dividend.len()) {
+        if let Some(len) = rem_limbs(dividend, divisor) {
+            return len;
+        }
+    }
+
+    // reverted trial subtraction leaves radix -1 places above remainder
+    let len = rem_core::<Dec>(dividend, divisor, None);
+    dividend[len..].fill(0);
+
+    len
+}
+
+// halves decimal places in place, returns remainder
+fn halve(num: &mut [u8]) -> u8 {
+    let mut rem = 0;
+
+    for ix in (0..num.len()).rev() {
+        let cur = rem * 10 + num[ix];
+
+        num[ix] = cur >> 1;
+        rem = cur & 1;
+    }
+
+    rem
+}
+
+fn clear_swap<'a, P: Place, const N: usize>(
+    mcand: &mut &'a mut [P; N],
+    mcand_len: usize,
+    sum: *mut &'a mut [P; N],
+) {
+    for ix in 0..mcand_len {
+        mcand[ix] = P::default();
+    }
+
+    let swap: *mut [P; N] = *mcand;
+
+    unsafe {
+        *mcand = *sum;
+        *sum = swap.as_mut().unwrap();
+    }
+}
+
+#[allow(clippy::needless_range_loop)]
+fn pow_linear<R: Radix, const N: usize>(
+    base: &[R::Place],
+    pow: u64,
+) -> Option<([R::Place; N], usize)> {
+    let zero = R::Place::default();
+    let mut aux1 = [zero; N];
+
+    if pow == 0 {
+        aux1[0] = R::Place::narrow(1);
+        return Some((aux1, 1));
+    }
+
+    let base_len = base.len();
+    aux1[..base_len].copy_from_slice(base);
+
+    if pow == 1 {
+        return Some((aux1, base_len));
+    }
+
+    let mut aux2 = [zero; N];
+
+    let mut mcand = &mut aux1;
+    let mut sum = &mut aux2;
+
+    let mut mcand_len = base_len;
+    let mut sum_len = 0;
+
+    let mut limit = (pow - 1) as usize;
+    loop {
+        for base_off in 0..base_len {
+            sum_len = muladd::<R>(&mcand[0..mcand_len], base[base_off], sum, base_off);
+
+            if sum_len > N {
+                return None;
+            }
+        }
+
+        mcand_len = sum_len;
+        limit -= 1;
+        if limit == 0 {
+            mcand = sum;
+            break;
+        }
+
+        clear_swap(&mut mcand, mcand_len, &mut sum);
+    }
+
+    Some((*mcand, mcand_len))
+}
+
+fn muladd<R: Radix>(
+    mcand: &[R::Place],
+    mpler: R::Place,
+    sum: &mut [R::Place],
+    base_off: usize,
+) -> usize {
+    let mut sum_max_ix = 0;
+
+    let mut ix = 0;
+    let mcand_len = mcand.len();
+
+    loop {
+        let prod = mpler.wide() * mcand[ix].wide();
+
+        let max_wr_ix = sumadd::<R>(prod, sum, base_off + ix);
+
+        if max_wr_ix > sum_max_ix {
+            sum_max_ix = max_wr_ix
+        };
+
+        ix += 1;
+
+        if ix == mcand_len {
+            break;
+        }
+    }
+
+    sum_max_ix + 1
+}
+
+fn sumadd<R: Radix>(mut addend: u64, sum: &mut [R::Place], mut off: usize) -> usize {
+    let mut takeover = 0;
+
+    loop {
+        // no place left, `off` points beyond `sum`
+        if off >= sum.len() {
+            break;
+        }
+
+        let augend = sum[off].wide();
+
+        sum[off] = ones::<R>(augend + addend, &mut takeover);
+
+        if takeover == 0 {
+            break;
+        } else {
+            addend = 0;
+            off += 1;
+        }
+    }
+
+    off
+}
+
+fn ones<R: Radix>(num: u64, takeover_ref: &mut u64) -> R::Place {
+    let mut takeover_val = *takeover_ref;
+    let total = num + takeover_val;
+
+    takeover_val = total / R::RADIX;
+    *takeover_ref = takeover_val;
+
+    R::Place::narrow(total - takeover_val * R::RADIX)
+}
+
+#[cfg(test)]
+mod tests_of_units {
+
+    mod herons_sqrt {
+        use crate::herons_sqrt;
+
+        #[test]
+        fn basic_test() {
+            assert_eq!(4, herons_sqrt(16));
+        }
+
+        #[test]
+        fn test_17() {
+            assert_eq!(4, herons_sqrt(17));
+        }
+
+        #[test]
+        fn test_24() {
+            assert_eq!(4, herons_sqrt(24));
+        }
+
+        #[test]
+        fn test_25() {
+            assert_eq!(5, herons_sqrt(25));
+        }
+
+        #[test]
+        fn load_test() {
+            assert_eq!(255, herons_sqrt(65025));
+        }
+
+        #[test]
+        fn one_test() {
+            assert_eq!(1, herons_sqrt(1));
+        }
+
+        #[test]
+        fn zero_test() {
+            assert_eq!(0, herons_sqrt(0));
+        }
+    }
+
+    mod herons_sqrt_widths {
+        use crate::{
+            herons_sqrt_u128, herons_sqrt_u32, herons_sqrt_u64, herons_sqrt_u8, herons_sqrt_usize,
+        };
+
+        #[test]
+        fn u8_test() {
+            assert_eq!(15, herons_sqrt_u8(255));
+            assert_eq!(3, herons_sqrt_u8(15));
+            assert_eq!(4, herons_sqrt_u8(16));
+        }
+
+        #[test]
+        fn u32_test() {
+            assert_eq!(65_535, herons_sqrt_u32(u32::MAX));
+            assert_eq!(65_534, herons_sqrt_u32(65_535 * 65_535 - 1));
+            assert_eq!(46_341, herons_sqrt_u32(46_341 * 46_341));
+        }
+
+        #[test]
+        fn u64_test() {
+            assert_eq!(u32::MAX as u64, herons_sqrt_u64(u64::MAX));
+            assert_eq!(1_000_000_000, herons_sqrt_u64(1_000_000_000_000_000_000));
+        }
+
+        #[test]
+        fn u128_test() {
+            assert_eq!(u64::MAX as u128, herons_sqrt_u128(u128::MAX));
+            assert_eq!(
+                u64::MAX as u128,
+                herons_sqrt_u128(u64::MAX as u128 * u64::MAX as u128)
+            );
+            assert_eq!(
+                u64::MAX as u128 - 1,
+                herons_sqrt_u128(u64::MAX as u128 * u64::MAX as u128 - 1)
+            );
+        }
+
+        #[test]
+        fn usize_test() {
+            assert_eq!(255, herons_sqrt_usize(65_535));
+            assert_eq!(0, herons_sqrt_usize(0));
+            assert_eq!(1, herons_sqrt_usize(3));
+        }
+    }
+
+    mod big_sqrt {
+        use crate::{big_sqrt, pow, to_decimals, AsSlice, BigDec};
+
+        fn big_sqrt_aux(num: u128) {
+            let decimals = to_decimals(num);
+            let (root, rem): (BigDec, BigDec) = big_sqrt(decimals.as_slice()).unwrap();
+
+            let root = crate::from_decimals(root.as_slice());
+            let rem = crate::from_decimals(rem.as_slice());
+
+            assert!(root * root <= num);
+            assert!((root + 1).checked_mul(root + 1).is_none_or(|sqr| sqr > num));
+            assert_eq!(num - root * root, rem);
+        }
+
+        #[test]
+        fn basic_test() {
+            big_sqrt_aux(16);
+        }
+
+        #[test]
+        fn zero_test() {
+            big_sqrt_aux(0);
+        }
+
+        #[test]
+        fn u16_test() {
+            big_sqrt_aux(65_535);
+        }
+
+        #[test]
+        fn square_test() {
+            big_sqrt_aux(65_536 * 65_536);
+        }
+
+        #[test]
+        fn near_square_test() {
+            big_sqrt_aux(65_536 * 65_536 - 1);
+        }
+
+        #[test]
+        fn odd_len_test() {
+            big_sqrt_aux(123_456_789);
+        }
+
+        #[test]
+        fn load_test() {
+            big_sqrt_aux(u128::MAX);
+        }
+
+        #[test]
+        fn big_test() {
+            // √10¹⁰⁰ = 10⁵⁰
+            let num = pow(&[0, 1], 100);
+            let proof = pow(&[0, 1], 50);
+
+            let (root, rem): (BigDec, BigDec) = big_sqrt(num.as_slice()).unwrap();
+            assert_eq!(proof.as_slice(), root.as_slice());
+            assert_eq!(&[0], rem.as_slice());
+        }
+
+        #[test]
+        fn big_rem_test() {
+            // √(10¹⁰⁰ -1) = 10⁵⁰ -1, rem = 2×10⁵⁰ -2
+            let num = [9; 100];
+            let proof = [9; 50];
+
+            let (root, rem): (BigDec, BigDec) = big_sqrt(&num).unwrap();
+            assert_eq!(proof, root.as_slice());
+
+            let mut rem_proof = [9; 51];
+            rem_proof[0] = 8;
+            rem_proof[50] = 1;
+            assert_eq!(rem_proof, rem.as_slice());
+        }
+
+        #[test]
+        fn leading_zeros_test() {
+            let (root, rem): (BigDec<1>, BigDec<1>) = big_sqrt(&[8, 0, 0]).unwrap();
+            assert_eq!(&[2], root.as_slice());
+            assert_eq!(&[4], rem.as_slice());
+        }
+
+        #[test]
+        fn capacity_test() {
+            let res: Option<(BigDec<5>, BigDec<5>)> = big_sqrt(&[0; 6]);
+            assert!(res.is_some());
+
+            let res: Option<(BigDec<5>, BigDec<5>)> = big_sqrt(&[1; 6]);
+            assert!(res.is_none());
+        }
+    }
+
+    #[allow(clippy::bool_assert_comparison)]
+    mod prime_ck {
+        use crate::prime_ck;
+
+        #[test]
+        fn basic_test() {
+            assert!(prime_ck(3));
+        }
+
+        #[test]
+        fn two_test() {
+            assert!(prime_ck(2));
+        }
+
+        #[test]
+        fn even_test() {
+            assert_eq!(false, prime_ck(256));
+        }
+
+        #[test]
+        fn test_65521() {
+            assert!(prime_ck(65521));
+        }
+
+        #[test]
+        fn test_49() {
+            assert_eq!(false, prime_ck(49));
+        }
+
+        #[test]
+        fn one_test() {
+            assert_eq!(false, prime_ck(1));
+        }
+
+        #[test]
+        fn zero_test() {
+            assert_eq!(false, prime_ck(0));
+        }
+    }
+
+    mod places_ck {
+        use crate::{places_ck, Error};
+
+        #[test]
+        fn basic_test() {
+            assert_eq!(Ok(()), places_ck(&[0, 9, 5]));
+        }
+
+        #[test]
+        fn empty_test() {
+            assert_eq!(Err(Error::EmptyInput), places_ck(&[]));
+        }
+
+        #[test]
+        fn invalid_digit_test() {
+            assert_eq!(Err(Error::InvalidDigit), places_ck(&[1, 10]));
+        }
+    }
+
+    mod big_dec {
+        use crate::{AsSlice, BigDec};
+
+        #[test]
+        fn from_places_test() {
+            let dec: BigDec<3> = BigDec::from_places(&[1, 2, 3]).unwrap();
+            assert_eq!(&[1, 2, 3], dec.as_slice());
+        }
+
+        #[test]
+        fn from_places_leading_zeros_test() {
+            let dec: BigDec<1> = BigDec::from_places(&[0, 0, 0]).unwrap();
+            assert_eq!(&[0], dec.as_slice());
+        }
+
+        #[test]
+        fn from_places_capacity_test() {
+            let dec: Option<BigDec<2>> = BigDec::from_places(&[1, 2, 3]);
+            assert!(dec.is_none());
+        }
+
+        #[test]
+        fn from_places_empty_test() {
+            let dec: BigDec<2> = BigDec::from_places(&[]).unwrap();
+            assert_eq!(&[0], dec.as_slice());
+        }
+    }
+
+    mod signed_big_dec {
+        use crate::{AsSlice, SignedBigDec};
+
+        #[test]
+        fn from_places_test() {
+            let dec: SignedBigDec<3> = SignedBigDec::from_places(true, &[1, 2, 0]).unwrap();
+            assert!(dec.is_neg());
+            assert_eq!(&[1, 2], dec.as_slice());
+        }
+
+        #[test]
+        fn negative_zero_test() {
+            let dec: SignedBigDec<3> = SignedBigDec::from_places(true, &[0, 0]).unwrap();
+            assert!(!dec.is_neg());
+            assert_eq!(&[0], dec.as_slice());
+        }
+
+        #[test]
+        fn from_places_capacity_test() {
+            let dec: Option<SignedBigDec<2>> = SignedBigDec::from_places(false, &[1, 2, 3]);
+            assert!(dec.is_none());
+        }
+
+        #[test]
+        fn opposite_test() {
+            let dec: SignedBigDec<3> = SignedBigDec::from_places(false, &[7]).unwrap();
+            assert!(dec.opposite().is_neg());
+            assert!(!dec.opposite().opposite().is_neg());
+            assert_eq!(&[7], dec.opposite().mag().as_slice());
+        }
+    }
+
+    mod to_decimals_i128 {
+        use crate::{to_decimals_i128, AsSlice};
+
+        #[test]
+        fn basic_test() {
+            let dec = to_decimals_i128(-1024);
+            assert!(dec.is_neg());
+            assert_eq!(&[4, 2, 0, 1], dec.as_slice());
+        }
+
+        #[test]
+        fn zero_test() {
+            let dec = to_decimals_i128(0);
+            assert!(!dec.is_neg());
+            assert_eq!(&[0], dec.as_slice());
+        }
+
+        #[test]
+        fn min_test() {
+            let dec = to_decimals_i128(i128::MIN);
+            assert!(dec.is_neg());
+            assert_eq!(
+                &[
+                    8, 2, 7, 5, 0, 1, 4, 8, 8, 5, 1, 7, 3, 0, 3, 7, 8, 6, 1, 3, 7, 1, 3, 2, 9, 6,
+                    4, 0, 6, 4, 3, 8, 1, 1, 4, 1, 0, 7, 1
+                ],
+                dec.as_slice()
+            );
+        }
+
+        #[test]
+        fn max_test() {
+            let dec = to_decimals_i128(i128::MAX);
+            assert!(!dec.is_neg());
+            assert_eq!(
+                &[
+                    7, 2, 7, 5, 0, 1, 4, 8, 8, 5, 1, 7, 3, 0, 3, 7, 8, 6, 1, 3, 7, 1, 3, 2, 9, 6,
+                    4, 0, 6, 4, 3, 8, 1, 1, 4, 1, 0, 7, 1
+                ],
+                dec.as_slice()
+            );
+        }
+    }
+
+    mod add_signed {
+        use crate::{add_signed, to_decimals_i128, AsSlice, SignedBigDec};
+
+        #[test]
+        fn same_signs_test() {
+            let sum = add_signed(&to_decimals_i128(-95), &to_decimals_i128(-7)).unwrap();
+            assert!(sum.is_neg());
+            assert_eq!(&[2, 0, 1], sum.as_slice());
+        }
+
+        #[test]
+        fn greater_negative_test() {
+            let sum = add_signed(&to_decimals_i128(7), &to_decimals_i128(-95)).unwrap();
+            assert!(sum.is_neg());
+            assert_eq!(&[8, 8], sum.as_slice());
+        }
+
+        #[test]
+        fn greater_positive_test() {
+            let sum = add_signed(&to_decimals_i128(-7), &to_decimals_i128(95)).unwrap();
+            assert!(!sum.is_neg());
+            assert_eq!(&[8, 8], sum.as_slice());
+        }
+
+        #[test]
+        fn zero_sum_test() {
+            let sum = add_signed(&to_decimals_i128(-95), &to_decimals_i128(95)).unwrap();
+            assert!(!sum.is_neg());
+            assert_eq!(&[0], sum.as_slice());
+        }
+
+        #[test]
+        fn overflow_test() {
+            let max: SignedBigDec<3> = SignedBigDec::from_places(true, &[9, 9, 9]).unwrap();
+            assert!(add_signed(&max, &max).is_none());
+        }
+    }
+
+    mod sub_signed {
+        use crate::{sub_signed, to_decimals_i128, AsSlice};
+
+        #[test]
+        fn basic_test() {
+            let dif = sub_signed(&to_decimals_i128(7), &to_decimals_i128(95)).unwrap();
+            assert!(dif.is_neg());
+            assert_eq!(&[8, 8], dif.as_slice());
+        }
+
+        #[test]
+        fn negative_subtrahend_test() {
+            let dif = sub_signed(&to_decimals_i128(7), &to_decimals_i128(-95)).unwrap();
+            assert!(!dif.is_neg());
+            assert_eq!(&[2, 0, 1], dif.as_slice());
+        }
+
+        #[test]
+        fn zero_dif_test() {
+            let dif = sub_signed(&to_decimals_i128(-7), &to_decimals_i128(-7)).unwrap();
+            assert!(!dif.is_neg());
+            assert_eq!(&[0], dif.as_slice());
+        }
+    }
+
+    mod mul_signed {
+        use crate::{mul_signed, to_decimals_i128, AsSlice};
+
+        #[test]
+        fn basic_test() {
+            let prod = mul_signed(&to_decimals_i128(-12), &to_decimals_i128(12)).unwrap();
+            assert!(prod.is_neg());
+            assert_eq!(&[4, 4, 1], prod.as_slice());
+        }
+
+        #[test]
+        fn negatives_test() {
+            let prod = mul_signed(&to_decimals_i128(-12), &to_decimals_i128(-12)).unwrap();
+            assert!(!prod.is_neg());
+            assert_eq!(&[4, 4, 1], prod.as_slice());
+        }
+
+        #[test]
+        fn zero_test() {
+            let prod = mul_signed(&to_decimals_i128(-12), &to_decimals_i128(0)).unwrap();
+            assert!(!prod.is_neg());
+            assert_eq!(&[0], prod.as_slice());
+        }
+    }
+
+    mod div_rem_trunc {
+        use crate::{div_rem_trunc, from_decimals, to_decimals_i128, AsSlice};
+
+        #[test]
+        fn basic_test() {
+            let ends = [-17, -12, -1, 0, 1, 12, 17];
+            let sors = [-5, -4, -1, 1, 4, 5];
+
+            for end in ends {
+                for sor in sors {
+                    let (quot, rem) =
+                        div_rem_trunc(&to_decimals_i128(end), &to_decimals_i128(sor)).unwrap();
+
+                    let mut q = from_decimals(quot.as_slice()) as i128;
+                    if quot.is_neg() {
+                        q = -q;
+                    }
+
+                    let mut r = from_decimals(rem.as_slice()) as i128;
+                    if rem.is_neg() {
+                        r = -r;
+                    }
+
+                    assert_eq!((end / sor, end % sor), (q, r), "{end} / {sor}");
+                }
+            }
+        }
+
+        #[test]
+        fn zero_divisor_test() {
+            let res = div_rem_trunc(&to_decimals_i128(-7), &to_decimals_i128(0));
+            assert!(res.is_none());
+        }
+    }
+
+    mod div_rem_floor {
+        use crate::{div_rem_floor, from_decimals, to_decimals_i128, AsSlice};
+
+        #[test]
+        fn basic_test() {
+            let ends = [-17, -12, -1, 0, 1, 12, 17];
+            let sors = [-5, -4, -1, 1, 4, 5];
+
+            for end in ends {
+                for sor in sors {
+                    let (quot, rem) =
+                        div_rem_floor(&to_decimals_i128(end), &to_decimals_i128(sor)).unwrap();
+
+                    let mut q = from_decimals(quot.as_slice()) as i128;
+                    if quot.is_neg() {
+                        q = -q;
+                    }
+
+                    let mut r = from_decimals(rem.as_slice()) as i128;
+                    if rem.is_neg() {
+                        r = -r;
+                    }
+
+                    // r has sign of divisor
+                    let (mut tq, mut tr) = (end / sor, end % sor);
+                    if tr != 0 && (tr < 0) != (sor < 0) {
+                        tq -= 1;
+                        tr += sor;
+                    }
+
+                    assert_eq!((tq, tr), (q, r), "{end} / {sor}");
+                }
+            }
+        }
+
+        #[test]
+        fn quotient_capacity_test() {
+            let end = to_decimals_i128(-99);
+            let sor = to_decimals_i128(1);
+            let (quot, rem) = div_rem_floor(&end, &sor).unwrap();
+            assert!(quot.is_neg());
+            assert_eq!(&[9, 9], quot.as_slice());
+            assert_eq!(&[0], rem.as_slice());
+        }
+
+        #[test]
+        fn zero_divisor_test() {
+            let res = div_rem_floor(&to_decimals_i128(-7), &to_decimals_i128(0));
+            assert!(res.is_none());
+        }
+    }
+
+    mod prime_ck_widths {
+        use crate::{prime_ck_u128, prime_ck_u32, prime_ck_u64, prime_ck_u8, prime_ck_usize};
+
+        #[test]
+        fn u8_test() {
+            assert!(prime_ck_u8(251));
+            assert!(!prime_ck_u8(255));
+            assert!(!prime_ck_u8(9));
+        }
+
+        #[test]
+        fn u32_test() {
+            assert!(prime_ck_u32(4_294_967_291));
+            assert!(!prime_ck_u32(u32::MAX));
+            assert!(!prime_ck_u32(65_537 * 65_521));
+        }
+
+        #[test]
+        fn u64_test() {
+            assert!(prime_ck_u64(1_000_000_007));
+            assert!(!prime_ck_u64(65_537 * 4_294_967_291));
+        }
+
+        #[test]
+        fn u64_max_prime_test() {
+            assert!(prime_ck_u64(18_446_744_073_709_551_557));
+            assert!(!prime_ck_u64(4_294_967_291 * 4_294_967_279));
+        }
+
+        #[test]
+        fn u128_test() {
+            assert!(prime_ck_u128(1_000_000_007));
+            assert!(!prime_ck_u128(u128::MAX));
+        }
+
+        #[test]
+        fn u128_mersenne_test() {
+            assert!(prime_ck_u128((1 << 127) - 1));
+        }
+
+        #[test]
+        fn usize_test() {
+            assert!(prime_ck_usize(65_521));
+            assert!(!prime_ck_usize(0));
+            assert!(!prime_ck_usize(1));
+            assert!(prime_ck_usize(2));
+            assert!(prime_ck_usize(4_294_967_291));
+        }
+    }
+
+    mod miller_rabin_u64 {
+        use crate::{miller_rabin_u64, prime_ck};
+
+        #[test]
+        fn basic_test() {
+            assert!(miller_rabin_u64(65_521));
+        }
+
+        #[test]
+        fn small_test() {
+            for n in 0..=u16::MAX {
+                assert_eq!(prime_ck(n), miller_rabin_u64(n as u64), "{}", n);
+            }
+        }
+
+        #[test]
+        fn max_prime_test() {
+            assert!(miller_rabin_u64(18_446_744_073_709_551_557));
+        }
+
+        #[test]
+        fn max_test() {
+            assert!(!miller_rabin_u64(u64::MAX));
+        }
+
+        #[test]
+        fn carmichael_test() {
+            assert!(!miller_rabin_u64(561));
+            assert!(!miller_rabin_u64(41_041));
+        }
+
+        #[test]
+        fn strong_pseudoprime_test() {
+            // strong pseudoprime to bases 2, 3, 5, 7
+            assert!(!miller_rabin_u64(3_215_031_751));
+        }
+
+        #[test]
+        fn semiprime_test() {
+            assert!(!miller_rabin_u64(4_294_967_291 * 4_294_967_279));
+        }
+    }
+
+    mod miller_rabin_u128 {
+        use crate::miller_rabin_u128;
+
+        #[test]
+        fn mersenne_test() {
+            assert!(miller_rabin_u128((1 << 89) - 1));
+            assert!(miller_rabin_u128((1 << 127) - 1));
+        }
+
+        #[test]
+        fn composite_mersenne_test() {
+            // 2¹²⁸ -1 = 3×5×17×257×641×65537×…
+            assert!(!miller_rabin_u128(u128::MAX));
+            // 2⁶⁷ -1 = 193707721×761838257287
+            assert!(!miller_rabin_u128((1 << 67) - 1));
+        }
+
+        #[test]
+        fn semiprime_test() {
+            let p = 18_446_744_073_709_551_557u128;
+            assert!(!miller_rabin_u128(p * 4_294_967_291));
+        }
+
+        #[test]
+        fn strong_pseudoprime_test() {
+            // strong pseudoprimes to all 12 first primes
+            // 399165290221×798330580441
+            assert!(!miller_rabin_u128(318_665_857_834_031_151_167_461));
+            // 1287836182261×2575672364521
+            assert!(!miller_rabin_u128(3_317_044_064_679_887_385_961_981));
+        }
+
+        #[test]
+        fn square_test() {
+            let p = 18_446_744_073_709_551_557u128;
+            assert!(!miller_rabin_u128(p * p));
+        }
+    }
+
+    mod lucas_ck_u128 {
+        use crate::lucas_ck_u128;
+
+        #[test]
+        fn prime_test() {
+            assert!(lucas_ck_u128(65_521));
+            assert!(lucas_ck_u128(18_446_744_073_709_551_557));
+        }
+
+        #[test]
+        fn composite_test() {
+            assert!(!lucas_ck_u128(65_537 * 65_521));
+            assert!(!lucas_ck_u128(3_215_031_751));
+        }
+
+        #[test]
+        fn square_test() {
+            assert!(!lucas_ck_u128(65_521 * 65_521));
+        }
+
+        #[test]
+        fn pseudoprime_test() {
+            // strong Lucas pseudoprimes
+            assert!(lucas_ck_u128(5_459));
+            assert!(lucas_ck_u128(5_777));
+        }
+    }
+
+    mod jacobi_u128 {
+        use crate::jacobi_u128;
+
+        #[test]
+        fn basic_test() {
+            assert_eq!(-1, jacobi_u128(1001, 9907));
+            assert_eq!(1, jacobi_u128(19, 45));
+            assert_eq!(-1, jacobi_u128(8, 21));
+            assert_eq!(1, jacobi_u128(30, 7));
+        }
+
+        #[test]
+        fn zero_test() {
+            assert_eq!(0, jacobi_u128(21, 7));
+            assert_eq!(0, jacobi_u128(0, 9));
+        }
+
+        #[test]
+        fn one_test() {
+            assert_eq!(1, jacobi_u128(5, 1));
+        }
+    }
+
+    mod half_mod_u128 {
+        use crate::half_mod_u128;
+
+        #[test]
+        fn basic_test() {
+            assert_eq!(3, half_mod_u128(6, 7));
+            assert_eq!(4, half_mod_u128(1, 7));
+        }
+
+        #[test]
+        fn load_test() {
+            let m = u128::MAX;
+            assert_eq!(m - 1, half_mod_u128(m - 2, m));
+        }
+    }
+
+    mod mul_mod_u128 {
+        use crate::mul_mod_u128;
+
+        #[test]
+        fn basic_test() {
+            assert_eq!(6, mul_mod_u128(4, 5, 7));
+        }
+
+        #[test]
+        fn load_test() {
+            let m = u128::MAX;
+            assert_eq!(1, mul_mod_u128(m - 1, m - 1, m));
+        }
+    }
+
+    mod miller_rabin_big {
+        use crate::{miller_rabin_big, prime_ck_u8, to_decimals, AsSlice};
+
+        #[test]
+        fn u128_test() {
+            let num = to_decimals((1 << 127) - 1);
+            assert_eq!(Some(true), miller_rabin_big::<80>(num.as_slice(), 5, 0));
+        }
+
+        #[test]
+        fn strong_pseudoprime_test() {
+            let num = to_decimals(318_665_857_834_031_151_167_461);
+            assert_eq!(Some(false), miller_rabin_big::<100>(num.as_slice(), 20, 7));
+        }
+
+        #[test]
+        fn small_test() {
+            for n in 0..=255 {
+                let num = to_decimals(n as u128);
+                let expected = Some(prime_ck_u8(n));
+                assert_eq!(
+                    expected,
+                    miller_rabin_big::<6>(num.as_slice(), 10, 3),
+                    "{}",
+                    n
+                );
+            }
+        }
+
+        #[test]
+        fn zero_rounds_test() {
+            let num = to_decimals(1_000_000_007);
+            assert_eq!(None, miller_rabin_big::<20>(num.as_slice(), 0, 0));
+        }
+
+        #[test]
+        fn empty_test() {
+            assert_eq!(None, miller_rabin_big::<20>(&[], 5, 0));
+        }
+
+        #[test]
+        fn invalid_place_test() {
+            assert_eq!(None, miller_rabin_big::<20>(&[7, 10], 5, 0));
+        }
+
+        #[test]
+        fn prime_test() {
+            // 10⁴⁰ +121
+            let mut num = [0; 41];
+            num[40] = 1;
+            num[2] = 1;
+            num[1] = 2;
+            num[0] = 1;
+
+            assert_eq!(Some(true), miller_rabin_big::<82>(&num, 5, 9119));
+        }
+
+        #[test]
+        fn semiprime_test() {
+            // 18446744073709551629×18446744073709551653
+            let num = [
+                7, 3, 7, 2, 9, 7, 5, 4, 2, 7, 1, 1, 1, 1, 8, 1, 1, 7, 5, 8, 3, 4, 6, 4, 8, 3, 9, 0,
+                2, 9, 6, 6, 3, 2, 8, 2, 0, 4, 3,
+            ];
+
+            assert_eq!(Some(false), miller_rabin_big::<80>(&num, 5, 9119));
+        }
+
+        #[test]
+        fn even_test() {
+            let mut num = [0; 41];
+            num[40] = 1;
+
+            assert_eq!(Some(false), miller_rabin_big::<82>(&num, 5, 0));
+        }
+
+        #[test]
+        fn capacity_test() {
+            let num = [1; 41];
+            assert_eq!(None, miller_rabin_big::<81>(&num, 5, 0));
+        }
+    }
+
+    mod gcd_prim {
+        use crate::{gcd_u128, gcd_u16, gcd_u32, gcd_u64, gcd_u8, gcd_usize};
+
+        #[test]
+        fn basic_test() {
+            assert_eq!(6, gcd_u8(48, 18));
+            assert_eq!(6, gcd_u16(18, 48));
+            assert_eq!(1, gcd_u32(4_294_967_291, 4_294_967_279));
+            assert_eq!(65_537, gcd_u64(65_537 * 3, 65_537 * 5));
+            assert_eq!(1 << 64, gcd_u128(1 << 64, u128::MAX - (1 << 64) + 1));
+            assert_eq!(7, gcd_usize(7, 0));
+        }
+
+        #[test]
+        fn zero_test() {
+            assert_eq!(0, gcd_u64(0, 0));
+            assert_eq!(5, gcd_u64(0, 5));
+        }
+    }
+
+    mod lcm_prim {
+        use crate::{lcm_u128, lcm_u64, lcm_u8};
+
+        #[test]
+        fn basic_test() {
+            assert_eq!(Some(36), lcm_u64(12, 18));
+            assert_eq!(Some(0), lcm_u64(0, 18));
+        }
+
+        #[test]
+        fn overflow_test() {
+            assert_eq!(None, lcm_u8(16, 17));
+            assert_eq!(Some(240), lcm_u8(16, 15));
+            assert_eq!(Some(u128::MAX), lcm_u128(u128::MAX, 5));
+        }
+    }
+
+    mod gcd_ext_prim {
+        use crate::{gcd_ext_u128, gcd_ext_u32, gcd_ext_u64, gcd_ext_u8};
+
+        fn gcd_ext_aux(a: u64, b: u64, g: u64) {
+            let (gcd, x, y) = gcd_ext_u64(a, b).unwrap();
+
+            assert_eq!(g, gcd);
+            assert_eq!(
+                gcd as u128,
+                a as u128 * x as u128 - b as u128 * y as u128,
+                "{} {}",
+                a,
+                b
+            );
+        }
+
+        #[test]
+        fn basic_test() {
+            gcd_ext_aux(3, 7, 1);
+            gcd_ext_aux(7, 3, 1);
+            gcd_ext_aux(240, 46, 2);
+            gcd_ext_aux(46, 240, 2);
+            gcd_ext_aux(5, 5, 5);
+            gcd_ext_aux(5, 0, 5);
+            gcd_ext_aux(1, 1_000_000_007, 1);
+        }
+
+        #[test]
+        fn load_test() {
+            gcd_ext_aux(u64::MAX, u64::MAX - 1, 1);
+            gcd_ext_aux(u64::MAX - 1, u64::MAX, 1);
+            gcd_ext_aux(4_294_967_291 * 3, 4_294_967_291 * 7, 4_294_967_291);
+        }
+
+        #[test]
+        fn zero_test() {
+            assert_eq!(Some((0, 0, 0)), gcd_ext_u8(0, 0));
+            assert_eq!(None, gcd_ext_u8(0, 5));
+        }
+
+        #[test]
+        fn max_test() {
+            let (g, x, y) = gcd_ext_u8(255, 254).unwrap();
+            assert_eq!(1, g);
+            assert_eq!(1, 255 * x as i32 - 254 * y as i32);
+
+            let (g, x, y) = gcd_ext_u32(u32::MAX, 65_536).unwrap();
+            assert_eq!(1, g);
+            assert_eq!(1, u32::MAX as i64 * x as i64 - 65_536 * y as i64);
+
+            let (g, x, _) = gcd_ext_u128(3, u128::MAX - 1).unwrap();
+            assert_eq!(1, g);
+            assert_eq!(1, 3 * x % (u128::MAX - 1));
+        }
+    }
+
+    mod mod_inv_prim {
+        use crate::{mod_inv_u128, mod_inv_u16, mod_inv_u64, mod_inv_u8};
+
+        #[test]
+        fn basic_test() {
+            assert_eq!(Some(4), mod_inv_u8(3, 11));
+            assert_eq!(Some(12), mod_inv_u16(10, 17));
+        }
+
+        #[test]
+        fn reduction_test() {
+            // 14 ≡ 3 (mod 11)
+            assert_eq!(Some(4), mod_inv_u64(14, 11));
+        }
+
+        #[test]
+        fn not_invertible_test() {
+            assert_eq!(None, mod_inv_u64(6, 9));
+            assert_eq!(None, mod_inv_u64(9, 9));
+            assert_eq!(None, mod_inv_u64(0, 9));
+        }
+
+        #[test]
+        fn modulus_edge_test() {
+            assert_eq!(None, mod_inv_u64(5, 0));
+            assert_eq!(Some(0), mod_inv_u64(5, 1));
+            assert_eq!(Some(1), mod_inv_u64(1, 2));
+        }
+
+        #[test]
+        fn load_test() {
+            let m = u128::MAX;
+            assert_eq!(Some(m / 2 + 1), mod_inv_u128(2, m));
+
+            let m = (1 << 127) - 1;
+            let inv = mod_inv_u128(u64::MAX as u128, m).unwrap();
+            assert_eq!(1, crate::mul_mod_u128(inv, u64::MAX as u128, m));
+        }
+
+        #[test]
+        fn u8_max_test() {
+            for a in 1..=250u8 {
+                let inv = mod_inv_u8(a, 251).unwrap();
+                assert_eq!(1, a as u16 * inv as u16 % 251);
+            }
+        }
+    }
+
+    mod mod_inv {
+        use crate::{mod_inv, to_decimals, try_mod_inv, AsSlice, AsSliceMut, BigDec, Error};
+
+        #[test]
+        fn basic_test() {
+            let inv: BigDec = mod_inv(&[3], &[1, 1]).unwrap();
+            assert_eq!(&[4], inv.as_slice());
+        }
+
+        #[test]
+        fn reduction_test() {
+            let inv: BigDec = mod_inv(&[4, 1], &[1, 1]).unwrap();
+            assert_eq!(&[4], inv.as_slice());
+        }
+
+        #[test]
+        fn not_invertible_test() {
+            let inv: Option<BigDec> = mod_inv(&[6], &[9]);
+            assert!(inv.is_none());
+
+            let inv: Option<BigDec> = mod_inv(&[0], &[9]);
+            assert!(inv.is_none());
+        }
+
+        #[test]
+        fn modulus_edge_test() {
+            let inv: Option<BigDec> = mod_inv(&[5], &[0]);
+            assert!(inv.is_none());
+
+            let inv: BigDec = mod_inv(&[5], &[1, 0]).unwrap();
+            assert_eq!(&[0], inv.as_slice());
+        }
+
+        #[test]
+        fn big_test() {
+            // 10⁴⁰ +121 is prime
+            let mut m = [0; 41];
+            m[40] = 1;
+            m[2] = 1;
+            m[1] = 2;
+            m[0] = 1;
+
+            let a = to_decimals(u128::MAX);
+            let inv: BigDec = mod_inv(a.as_slice(), &m).unwrap();
+
+            let mut prod: BigDec = crate::mul(a.as_slice(), inv.as_slice()).unwrap();
+            let len = crate::rem_big(prod.as_slice_mut(), &m).unwrap();
+            assert_eq!([1], prod.0[..len]);
+        }
+
+        #[test]
+        fn try_test() {
+            let inv: Result<BigDec, Error> = try_mod_inv(&[3], &[1, 1]);
+            assert_eq!(&[4], inv.unwrap().as_slice());
+
+            let inv: Result<BigDec, Error> = try_mod_inv(&[6], &[9]);
+            assert_eq!(Err(Error::NotInvertible), inv.map(|_| ()));
+
+            let inv: Result<BigDec, Error> = try_mod_inv(&[6], &[0]);
+            assert_eq!(Err(Error::DivisionByZero), inv.map(|_| ()));
+
+            let inv: Result<BigDec<1>, Error> = try_mod_inv(&[3], &[1, 1]);
+            assert_eq!(Err(Error::Overflow), inv.map(|_| ()));
+
+            let inv: Result<BigDec, Error> = try_mod_inv(&[3], &[]);
+            assert_eq!(Err(Error::EmptyInput), inv.map(|_| ()));
+        }
+    }
+
+    mod gcd {
+        use crate::{gcd, pow, to_decimals, AsSlice, BigDec};
+
+        #[test]
+        fn basic_test() {
+            let g: BigDec = gcd(&[8, 4], &[8, 1]).unwrap();
+            assert_eq!(&[6], g.as_slice());
+        }
+
+        #[test]
+        fn coprime_test() {
+            let a = to_decimals(4_294_967_291);
+            let b = to_decimals(4_294_967_279);
+
+            let g: BigDec = gcd(a.as_slice(), b.as_slice()).unwrap();
+            assert_eq!(&[1], g.as_slice());
+        }
+
+        #[test]
+        fn zero_test() {
+            let g: BigDec = gcd(&[0], &[5, 1]).unwrap();
+            assert_eq!(&[5, 1], g.as_slice());
+
+            let g: BigDec = gcd(&[5, 1], &[0, 0]).unwrap();
+            assert_eq!(&[5, 1], g.as_slice());
+
+            let g: BigDec = gcd(&[0], &[0]).unwrap();
+            assert_eq!(&[0], g.as_slice());
+        }
+
+        #[test]
+        fn big_test() {
+            // gcd(2¹⁵⁰×3, 2¹⁰⁰×5) = 2¹⁰⁰
+            let a = pow(&[2], 150);
+            let a: BigDec = crate::mul(a.as_slice(), &[3]).unwrap();
+            let b = pow(&[2], 100);
+            let proof = b;
+            let b: BigDec = crate::mul(b.as_slice(), &[5]).unwrap();
+
+            let g: BigDec = gcd(a.as_slice(), b.as_slice()).unwrap();
+            assert_eq!(proof.as_slice(), g.as_slice());
+        }
+
+        #[test]
+        fn capacity_test() {
+            let g: Option<BigDec<2>> = gcd(&[0, 0, 1], &[5]);
+            assert!(g.is_none());
+        }
+    }
+
+    mod lcm {
+        use crate::{lcm, to_decimals, AsSlice, BigDec};
+
+        #[test]
+        fn basic_test() {
+            let l: BigDec = lcm(&[2, 1], &[8, 1]).unwrap();
+            assert_eq!(&[6, 3], l.as_slice());
+        }
+
+        #[test]
+        fn zero_test() {
+            let l: BigDec = lcm(&[0], &[8, 1]).unwrap();
+            assert_eq!(&[0], l.as_slice());
+        }
+
+        #[test]
+        fn big_test() {
+            let a = to_decimals(4_294_967_291 * 6);
+            let b = to_decimals(4_294_967_279 * 4);
+            let proof = to_decimals(4_294_967_291 * 4_294_967_279 * 12);
+
+            let l: BigDec = lcm(a.as_slice(), b.as_slice()).unwrap();
+            assert_eq!(proof.as_slice(), l.as_slice());
+        }
+
+        #[test]
+        fn overflow_test() {
+            let l: Option<BigDec<2>> = lcm(&[1, 1], &[3, 1]);
+            assert!(l.is_none());
+        }
+    }
+
+    mod gcd_ext {
+        use crate::{gcd_ext, pow, to_decimals, AsSlice, BigDec};
+
+        fn gcd_ext_aux(a: u128, b: u128) {
+            let a_dec = to_decimals(a);
+            let b_dec = to_decimals(b);
+
+            let (g, x, y): (BigDec, BigDec, BigDec) =
+                gcd_ext(a_dec.as_slice(), b_dec.as_slice()).unwrap();
+
+            let g = crate::from_decimals(g.as_slice());
+            let x = crate::from_decimals(x.as_slice());
+            let y = crate::from_decimals(y.as_slice());
+
+            assert_eq!(crate::gcd_u128(a, b), g);
+            assert_eq!(g, a * x - b * y);
+        }
+
+        #[test]
+        fn basic_test() {
+            gcd_ext_aux(3, 7);
+            gcd_ext_aux(7, 3);
+            gcd_ext_aux(240, 46);
+            gcd_ext_aux(5, 5);
+            gcd_ext_aux(5, 0);
+        }
+
+        #[test]
+        fn load_test() {
+            gcd_ext_aux(4_294_967_291, 4_294_967_279);
+            gcd_ext_aux(65_537 * 65_521, 65_537 * 257);
+        }
+
+        #[test]
+        fn zero_test() {
+            let res: Option<(BigDec, BigDec, BigDec)> = gcd_ext(&[0], &[5]);
+            assert!(res.is_none());
+
+            let (g, x, y): (BigDec, BigDec, BigDec) = gcd_ext(&[0], &[0]).unwrap();
+            assert_eq!(&[0], g.as_slice());
+            assert_eq!(&[0], x.as_slice());
+            assert_eq!(&[0], y.as_slice());
+        }
+
+        #[test]
+        fn big_test() {
+            // 2¹⁵⁰ and 3⁹⁰ are coprime
+            let a = pow(&[2], 150);
+            let b = pow(&[3], 90);
+
+            let (g, x, y): (BigDec, BigDec, BigDec) = gcd_ext(a.as_slice(), b.as_slice()).unwrap();
+            assert_eq!(&[1], g.as_slice());
+
+            let ax: BigDec = crate::mul(a.as_slice(), x.as_slice()).unwrap();
+            let by: BigDec = crate::mul(b.as_slice(), y.as_slice()).unwrap();
+            let diff: BigDec = crate::sub(ax.as_slice(), by.as_slice()).unwrap();
+            assert_eq!(&[1], diff.as_slice());
+        }
+    }
+
+    mod factorize_u64 {
+        use crate::factorize_u64;
+
+        fn factorize_aux(num: u64, proof: &[(u64, u8)]) {
+            let (facs, len) = factorize_u64(num);
+
+            assert_eq!(proof.len(), len);
+            assert_eq!(proof, &facs[..len]);
+        }
+
+        #[test]
+        fn basic_test() {
+            factorize_aux(360, &[(2, 3), (3, 2), (5, 1)]);
+        }
+
+        #[test]
+        fn zero_one_test() {
+            factorize_aux(0, &[]);
+            factorize_aux(1, &[]);
+        }
+
+        #[test]
+        fn prime_test() {
+            factorize_aux(65_521, &[(65_521, 1)]);
+            factorize_aux(
+                18_446_744_073_709_551_557,
+                &[(18_446_744_073_709_551_557, 1)],
+            );
+        }
+
+        #[test]
+        fn power_test() {
+            factorize_aux(1 << 63, &[(2, 63)]);
+        }
+
+        #[test]
+        fn max_test() {
+            let proof = [
+                (3, 1),
+                (5, 1),
+                (17, 1),
+                (257, 1),
+                (641, 1),
+                (65_537, 1),
+                (6_700_417, 1),
+            ];
+            factorize_aux(u64::MAX, &proof);
+        }
+
+        #[test]
+        fn semiprime_test() {
+            factorize_aux(
+                4_294_967_291 * 4_294_967_279,
+                &[(4_294_967_279, 1), (4_294_967_291, 1)],
+            );
+        }
+
+        #[test]
+        fn square_test() {
+            factorize_aux(4_294_967_291 * 4_294_967_291, &[(4_294_967_291, 2)]);
+        }
+
+        #[test]
+        fn mixed_test() {
+            // 2²×997×1009×1000003
+            factorize_aux(
+                4 * 997 * 1_009 * 1_000_003,
+                &[(2, 2), (997, 1), (1_009, 1), (1_000_003, 1)],
+            );
+        }
+
+        #[test]
+        fn three_large_test() {
+            factorize_aux(
+                1_009 * 1_013 * 1_000_003,
+                &[(1_009, 1), (1_013, 1), (1_000_003, 1)],
+            );
+        }
+
+        #[test]
+        fn distinct_max_test() {
+            let primes = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47];
+            let num = primes.iter().product();
+
+            let (facs, len) = factorize_u64(num);
+            assert_eq!(15, len);
+
+            for ix in 0..len {
+                assert_eq!((primes[ix], 1), facs[ix]);
+            }
+        }
+    }
+
+    mod factorize_u16 {
+        use crate::factorize_u16;
+
+        #[test]
+        fn basic_test() {
+            let (facs, len) = factorize_u16(u16::MAX);
+
+            assert_eq!(4, len);
+            assert_eq!([(3, 1), (5, 1), (17, 1), (257, 1)], facs[..4]);
+        }
+
+        #[test]
+        fn distinct_max_test() {
+            let (facs, len) = factorize_u16(30_030);
+
+            assert_eq!(6, len);
+            assert_eq!([(2, 1), (3, 1), (5, 1), (7, 1), (11, 1), (13, 1)], facs);
+        }
+    }
+
+    mod factorize_u32 {
+        use crate::factorize_u32;
+
+        #[test]
+        fn basic_test() {
+            let (facs, len) = factorize_u32(u32::MAX);
+
+            assert_eq!(5, len);
+            assert_eq!([(3, 1), (5, 1), (17, 1), (257, 1), (65_537, 1)], facs[..5]);
+        }
+
+        #[test]
+        fn prime_test() {
+            let (facs, len) = factorize_u32(4_294_967_291);
+
+            assert_eq!(1, len);
+            assert_eq!((4_294_967_291, 1), facs[0]);
+        }
+    }
+
+    mod pollards_rho {
+        use crate::pollards_rho;
+
+        #[test]
+        fn basic_test() {
+            let d = pollards_rho(8_051);
+            assert!(d == 83 || d == 97);
+        }
+
+        #[test]
+        fn load_test() {
+            let num = 4_294_967_291 * 4_294_967_279;
+            let d = pollards_rho(num);
+
+            assert!(d == 4_294_967_291 || d == 4_294_967_279);
+        }
+    }
+
+    mod sieve_segment {
+        use crate::{bit_ck, prime_ck, sieve_segment};
+
+        #[test]
+        fn basic_test() {
+            let mut bitmap = [0; 2];
+            sieve_segment(&mut bitmap, 0);
+
+            // 2, 3, 5, 7 | 11, 13
+            assert_eq!([0b1010_1100, 0b0010_1000], bitmap);
+        }
+
+        #[test]
+        fn segment_test() {
+            let mut bitmap = [0; 16];
+            sieve_segment(&mut bitmap, 60_000);
+
+            for ix in 0..128 {
+                let num = 60_000 + ix as u16;
+                assert_eq!(prime_ck(num), bit_ck(&bitmap, ix), "{}", num);
+            }
+        }
+
+        #[test]
+        fn odd_low_test() {
+            let mut bitmap = [0; 1];
+            sieve_segment(&mut bitmap, 1);
+
+            // 2, 3, 5, 7
+            assert_eq!(0b0101_0110, bitmap[0]);
+        }
+
+        #[test]
+        fn empty_test() {
+            sieve_segment(&mut [], 1_000);
+        }
+    }
+
+    mod sieve_segment_by {
+        use crate::{sieve_segment, sieve_segment_by};
+
+        #[test]
+        fn basic_test() {
+            let base = [2, 3, 5, 7, 11, 13];
+            let mut by_base = [0; 8];
+            let mut by_odds = [0; 8];
+
+            sieve_segment_by(&mut by_base, 100, &base);
+            sieve_segment(&mut by_odds, 100);
+
+            assert_eq!(by_odds, by_base);
+        }
+
+        #[test]
+        fn short_base_test() {
+            let mut by_base = [0; 32];
+            let mut by_odds = [0; 32];
+
+            // odd numbers past 3 complete sieving
+            sieve_segment_by(&mut by_base, 10_000, &[2, 3]);
+            sieve_segment(&mut by_odds, 10_000);
+
+            assert_eq!(by_odds, by_base);
+        }
+    }
+
+    mod cross_out {
+        use crate::cross_out;
+
+        #[test]
+        fn basic_test() {
+            let mut bitmap = [u8::MAX; 2];
+            assert!(cross_out(&mut bitmap, 0, 15, 3));
+
+            // 9, 12, 15
+            assert_eq!([u8::MAX, 0b0110_1101], bitmap);
+        }
+
+        #[test]
+        fn beyond_test() {
+            let mut bitmap = [u8::MAX; 1];
+            assert!(!cross_out(&mut bitmap, 0, 7, 3));
+            assert_eq!([u8::MAX], bitmap);
+        }
+
+        #[test]
+        fn max_test() {
+            let mut bitmap = [u8::MAX; 1];
+            assert!(cross_out(&mut bitmap, u64::MAX - 7, u64::MAX, 3));
+
+            // u64::MAX - 6, u64::MAX - 3, u64::MAX
+            assert_eq!(0b0110_1101, bitmap[0]);
+        }
+
+        #[test]
+        fn square_overflow_test() {
+            let mut bitmap = [u8::MAX; 1];
+            assert!(!cross_out(&mut bitmap, u64::MAX - 7, u64::MAX, 1 << 32));
+        }
+    }
+
+    mod sieve {
+        use crate::{bit_ck, prime_ck, sieve};
+
+        #[test]
+        fn basic_test() {
+            let mut bitmap = [0; 13];
+            assert_eq!(Some(25), sieve(&mut bitmap, 100));
+
+            for ix in 0..=100 {
+                assert_eq!(prime_ck(ix as u16), bit_ck(&bitmap, ix), "{}", ix);
+            }
+
+            for ix in 101..104 {
+                assert!(!bit_ck(&bitmap, ix));
+            }
+        }
+
+        #[test]
+        fn load_test() {
+            let mut bitmap = [0; 8192];
+            assert_eq!(Some(6542), sieve(&mut bitmap, 65_535));
+        }
+
+        #[test]
+        fn limit_test() {
+            let mut bitmap = [0; 1];
+            assert_eq!(Some(4), sieve(&mut bitmap, 7));
+            assert_eq!(None, sieve(&mut bitmap, 8));
+        }
+
+        #[test]
+        fn zero_test() {
+            let mut bitmap = [0; 1];
+            assert_eq!(Some(0), sieve(&mut bitmap, 0));
+        }
+    }
+
+    mod primes_into {
+        use crate::primes_into;
+
+        #[test]
+        fn basic_test() {
+            let mut list = [0; 10];
+
+            assert_eq!(4, primes_into(&mut list, 10));
+            assert_eq!([2, 3, 5, 7], list[..4]);
+        }
+
+        #[test]
+        fn full_list_test() {
+            let mut list = [0; 3];
+
+            assert_eq!(3, primes_into(&mut list, 1_000));
+            assert_eq!([2, 3, 5], list);
+        }
+
+        #[test]
+        fn max_test() {
+            let mut list = [0; 7000];
+
+            assert_eq!(6542, primes_into(&mut list, u16::MAX));
+            assert_eq!(65_521, list[6541]);
+        }
+    }
+
+    mod primes_up_to {
+        use crate::{miller_rabin_u64, primes_up_to};
+
+        #[test]
+        fn basic_test() {
+            let mut primes = primes_up_to(13);
+
+            for p in [2, 3, 5, 7, 11, 13] {
+                assert_eq!(Some(p), primes.next());
+            }
+
+            assert_eq!(None, primes.next());
+        }
+
+        #[test]
+        fn count_test() {
+            assert_eq!(1229, primes_up_to(10_000).count());
+        }
+
+        #[test]
+        fn base_test() {
+            // segments beyond first are sieved by kept primes
+            assert_eq!(9592, primes_up_to(100_000).count());
+            assert_eq!(Some(99_991), primes_up_to(100_000).last());
+        }
+
+        #[test]
+        fn segments_test() {
+            let mut last = 0;
+
+            for p in primes_up_to(5_000) {
+                assert!(miller_rabin_u64(p));
+
+                for n in last + 1..p {
+                    assert!(!miller_rabin_u64(n));
+                }
+
+                last = p;
+            }
+
+            assert_eq!(4_999, last);
+        }
+
+        #[test]
+        fn zero_test() {
+            assert_eq!(None, primes_up_to(1).next());
+        }
+    }
+
+    mod to_decimals {
+        use crate::{to_decimals, AsSlice, MAX_DEC_PLACES};
+
+        #[test]
+        fn basic_test() {
+            let decimals = to_decimals(1);
+            assert_eq!(1, decimals.1);
+            let mut proof = [0; MAX_DEC_PLACES];
+            proof[0] = 1;
+
+            assert_eq!(&proof, &decimals.0);
+        }
+
+        #[test]
+        fn zero_test() {
+            let decimals = to_decimals(0);
+            assert_eq!(1, decimals.1);
+            assert_eq!([0; MAX_DEC_PLACES], decimals.0);
+        }
+
+        #[test]
+        fn test_65535() {
+            let decimals = to_decimals(65535);
+            assert_eq!(5, decimals.1);
+            assert_eq!([5, 3, 5, 5, 6], decimals.as_slice());
+        }
+    }
+
+    mod from_decimals {
+        use crate::{from_decimals, to_decimals, AsSlice};
+
+        #[test]
+        fn basic_test() {
+            assert_eq!(1, from_decimals(&[1]));
+        }
+
+        #[test]
+        fn zero_test() {
+            assert_eq!(0, from_decimals(&[0]));
+        }
+
+        #[test]
+        fn zero_place_test() {
+            assert_eq!(101, from_decimals(&[1, 0, 1]));
+        }
+
+        #[test]
+        fn test_65535() {
+            assert_eq!(u16::MAX as u128, from_decimals(&[5, 3, 5, 5, 6]));
+        }
+
+        #[test]
+        fn leading_zeros_test() {
+            let mut decimals = [0; 50];
+            decimals[1] = 4;
+
+            assert_eq!(40, from_decimals(&decimals));
+        }
+
+        #[test]
+        fn max_test() {
+            let decimals = to_decimals(u128::MAX);
+            assert_eq!(u128::MAX, from_decimals(decimals.as_slice()));
+        }
+
+        #[test]
+        fn wrap_test() {
+            let mut decimals = to_decimals(u128::MAX);
+            decimals.0[0] += 1;
+
+            assert_eq!(0, from_decimals(decimals.as_slice()));
+        }
+    }
+
+    mod try_from_decimals {
+        use crate::{to_decimals, try_from_decimals, AsSlice, Error};
+
+        #[test]
+        fn basic_test() {
+            assert_eq!(Ok(101), try_from_decimals(&[1, 0, 1]));
+        }
+
+        #[test]
+        fn max_test() {
+            let decimals = to_decimals(u128::MAX);
+            assert_eq!(Ok(u128::MAX), try_from_decimals(decimals.as_slice()));
+        }
+
+        #[test]
+        fn leading_zeros_test() {
+            let mut decimals = [0; 50];
+            decimals[0] = 7;
+
+            assert_eq!(Ok(7), try_from_decimals(&decimals));
+        }
+
+        #[test]
+        fn overflow_test() {
+            let mut decimals = to_decimals(u128::MAX);
+            decimals.0[0] += 1;
+
+            assert_eq!(Err(Error::Overflow), try_from_decimals(decimals.as_slice()));
+        }
+
+        #[test]
+        fn place_overflow_test() {
+            let mut decimals = [0; 40];
+            decimals[39] = 1;
+
+            assert_eq!(Err(Error::Overflow), try_from_decimals(&decimals));
+        }
+
+        #[test]
+        fn nines_test() {
+            assert_eq!(Err(Error::Overflow), try_from_decimals(&[9; 39]));
+            assert_eq!(Ok(10u128.pow(38) - 1), try_from_decimals(&[9; 38]));
+        }
+
+        #[test]
+        fn invalid_digit_test() {
+            assert_eq!(Err(Error::InvalidDigit), try_from_decimals(&[11]));
+        }
+
+        #[test]
+        fn empty_test() {
+            assert_eq!(Err(Error::EmptyInput), try_from_decimals(&[]));
+        }
+    }
+
+    mod parse_decimal {
+        use crate::{parse_decimal, AsSlice, BigDec, ParseError};
+
+        #[test]
+        fn basic_test() {
+            let num: BigDec<5> = parse_decimal("12345").unwrap();
+            assert_eq!(&[5, 4, 3, 2, 1], num.as_slice());
+        }
+
+        #[test]
+        fn bytes_test() {
+            let num: BigDec<3> = parse_decimal(b"+101").unwrap();
+            assert_eq!(&[1, 0, 1], num.as_slice());
+        }
+
+        #[test]
+        fn underscores_test() {
+            let num: BigDec<7> = parse_decimal("1_000_000_").unwrap();
+            assert_eq!(&[0, 0, 0, 0, 0, 0, 1], num.as_slice());
+        }
+
+        #[test]
+        fn leading_zeros_test() {
+            let num: BigDec<2> = parse_decimal("0000_0042").unwrap();
+            assert_eq!(&[2, 4], num.as_slice());
+        }
+
+        #[test]
+        fn zero_test() {
+            let num: BigDec<1> = parse_decimal("-000").unwrap();
+            assert_eq!(&[0], num.as_slice());
+        }
+
+        #[test]
+        fn negative_test() {
+            assert_eq!(
+                Err(ParseError::Negative),
+                parse_decimal::<3>("-1").map(|_| ())
+            );
+        }
+
+        #[test]
+        fn empty_test() {
+            assert_eq!(Err(ParseError::Empty), parse_decimal::<3>("").map(|_| ()));
+            assert_eq!(Err(ParseError::Empty), parse_decimal::<3>("+").map(|_| ()));
+        }
+
+        #[test]
+        fn invalid_char_test() {
+            assert_eq!(
+                Err(ParseError::InvalidChar(2)),
+                parse_decimal::<3>("12a").map(|_| ())
+            );
+            assert_eq!(
+                Err(ParseError::InvalidChar(0)),
+                parse_decimal::<3>("_1").map(|_| ())
+            );
+            assert_eq!(
+                Err(ParseError::InvalidChar(1)),
+                parse_decimal::<3>("+_1").map(|_| ())
+            );
+            assert_eq!(
+                Err(ParseError::InvalidChar(1)),
+                parse_decimal::<3>("1-").map(|_| ())
+            );
+            assert_eq!(
+                Err(ParseError::InvalidChar(0)),
+                parse_decimal::<3>(" 1").map(|_| ())
+            );
+        }
+
+        #[test]
+        fn overflow_test() {
+            assert_eq!(
+                Err(ParseError::Overflow),
+                parse_decimal::<3>("1000").map(|_| ())
+            );
+            assert_eq!(
+                Err(ParseError::Overflow),
+                parse_decimal::<0>("0").map(|_| ())
+            );
+        }
+
+        #[test]
+        fn load_test() {
+            let num: BigDec = parse_decimal("340282366920938463463374607431768211455").unwrap();
+            assert_eq!(crate::to_decimals(u128::MAX).as_slice(), num.as_slice());
+        }
+    }
+
+    mod parse_signed_decimal {
+        use crate::{parse_signed_decimal, AsSlice, ParseError, SignedBigDec};
+
+        #[test]
+        fn basic_test() {
+            let num: SignedBigDec<4> = parse_signed_decimal("-1_024").unwrap();
+            assert!(num.is_neg());
+            assert_eq!(&[4, 2, 0, 1], num.as_slice());
+        }
+
+        #[test]
+        fn positive_test() {
+            let num: SignedBigDec<2> = parse_signed_decimal("+42").unwrap();
+            assert!(!num.is_neg());
+            assert_eq!(&[2, 4], num.as_slice());
+        }
+
+        #[test]
+        fn negative_zero_test() {
+            let num: SignedBigDec<2> = parse_signed_decimal("-0").unwrap();
+            assert!(!num.is_neg());
+            assert_eq!(&[0], num.as_slice());
+        }
+
+        #[test]
+        fn double_sign_test() {
+            assert_eq!(
+                Err(ParseError::InvalidChar(1)),
+                parse_signed_decimal::<3>("--1").map(|_| ())
+            );
+        }
+    }
+
+    mod write_decimal {
+        use crate::write_decimal;
+
+        #[test]
+        fn basic_test() {
+            let mut buf = [0; 5];
+            assert_eq!(
+                Some("12345"),
+                write_decimal(&[5, 4, 3, 2, 1], &mut buf, None)
+            );
+        }
+
+        #[test]
+        fn leading_zeros_test() {
+            let mut buf = [0; 2];
+            assert_eq!(Some("42"), write_decimal(&[2, 4, 0, 0], &mut buf, None));
+        }
+
+        #[test]
+        fn zero_test() {
+            let mut buf = [0; 1];
+            assert_eq!(Some("0"), write_decimal(&[0, 0], &mut buf, None));
+            assert_eq!(Some("0"), write_decimal(&[], &mut buf, None));
+        }
+
+        #[test]
+        fn separator_test() {
+            let mut buf = [0; 16];
+            assert_eq!(Some("1"), write_decimal(&[1], &mut buf, Some(b',')));
+            assert_eq!(Some("999"), write_decimal(&[9, 9, 9], &mut buf, Some(b',')));
+            assert_eq!(
+                Some("1_000"),
+                write_decimal(&[0, 0, 0, 1], &mut buf, Some(b'_'))
+            );
+            assert_eq!(
+                Some("12,345,678"),
+                write_decimal(&[8, 7, 6, 5, 4, 3, 2, 1], &mut buf, Some(b','))
+            );
+        }
+
+        #[test]
+        fn short_buffer_test() {
+            let mut buf = [0; 4];
+            assert_eq!(None, write_decimal(&[0, 0, 0, 1], &mut buf, Some(b',')));
+            assert_eq!(None, write_decimal(&[0, 0, 0, 0, 1], &mut buf, None));
+        }
+
+        #[test]
+        fn invalid_place_test() {
+            let mut buf = [0; 4];
+            assert_eq!(None, write_decimal(&[10, 1], &mut buf, None));
+        }
+
+        #[test]
+        fn non_ascii_separator_test() {
+            let mut buf = [0; 8];
+            assert_eq!(None, write_decimal(&[0, 0, 0, 1], &mut buf, Some(0xC2)));
+        }
+    }
+
+    mod big_dec_fmt {
+        use crate::{parse_decimal, parse_signed_decimal, BigDec, SignedBigDec};
+        use core::fmt::Write;
+
+        struct Buf([u8; 64], usize);
+
+        impl Write for Buf {
+            fn write_str(&mut self, s: &str) -> core::fmt::Result {
+                let end = self.1 + s.len();
+                self.0[self.1..end].copy_from_slice(s.as_bytes());
+                self.1 = end;
+                Ok(())
+            }
+        }
+
+        impl Buf {
+            fn as_str(&self) -> &str {
+                core::str::from_utf8(&self.0[..self.1]).unwrap()
+            }
+        }
+
+        #[test]
+        fn display_test() {
+            let num: BigDec<6> = parse_decimal("1_024").unwrap();
+            let mut buf = Buf([0; 64], 0);
+
+            write!(buf, "{}", num).unwrap();
+            assert_eq!("1024", buf.as_str());
+        }
+
+        #[test]
+        fn display_padding_test() {
+            let num: BigDec<6> = parse_decimal("42").unwrap();
+            let mut buf = Buf([0; 64], 0);
+
+            write!(buf, "{:>5}|{:05}|{:+}", num, num, num).unwrap();
+            assert_eq!("   42|00042|+42", buf.as_str());
+        }
+
+        #[test]
+        fn debug_test() {
+            let num: BigDec<4> = parse_decimal("120").unwrap();
+            let mut buf = Buf([0; 64], 0);
+
+            write!(buf, "{:?}", num).unwrap();
+            assert_eq!("BigDec { places: [0, 2, 1], len: 3 }", buf.as_str());
+        }
+
+        #[test]
+        fn signed_display_test() {
+            let neg: SignedBigDec<4> = parse_signed_decimal("-1024").unwrap();
+            let pos: SignedBigDec<4> = parse_signed_decimal("7").unwrap();
+            let mut buf = Buf([0; 64], 0);
+
+            write!(buf, "{} {} {:06}", neg, pos, neg).unwrap();
+            assert_eq!("-1024 7 -01024", buf.as_str());
+        }
+
+        #[test]
+        fn signed_debug_test() {
+            let neg: SignedBigDec<4> = parse_signed_decimal("-7").unwrap();
+            let mut buf = Buf([0; 64], 0);
+
+            write!(buf, "{:?}", neg).unwrap();
+            assert_eq!(
+                "SignedBigDec { neg: true, mag: BigDec { places: [7], len: 1 } }",
+                buf.as_str()
+            );
+        }
+
+        #[test]
+        fn invalid_place_test() {
+            let num: BigDec<3> = BigDec::from_places(&[11, 4]).unwrap();
+            let mut buf = Buf([0; 64], 0);
+
+            write!(buf, "{} {:?}", num, num).unwrap();
+            assert_eq!("4? BigDec { places: [11, 4], len: 2 }", buf.as_str());
+        }
+    }
+
+    mod from_be_bytes {
+        use crate::{from_be_bytes, from_le_bytes, to_decimals, AsSlice, BigDec};
+
+        #[test]
+        fn basic_test() {
+            let num: BigDec<5> = from_be_bytes(&[0x01, 0x00]).unwrap();
+            assert_eq!(&[6, 5, 2], num.as_slice());
+        }
+
+        #[test]
+        fn le_test() {
+            let num: BigDec<5> = from_le_bytes(&[0x01, 0x00]).unwrap();
+            assert_eq!(&[1], num.as_slice());
+        }
+
+        #[test]
+        fn zero_test() {
+            let num: BigDec<1> = from_be_bytes(&[0, 0, 0]).unwrap();
+            assert_eq!(&[0], num.as_slice());
+
+            let num: BigDec<1> = from_be_bytes(&[]).unwrap();
+            assert_eq!(&[0], num.as_slice());
+        }
+
+        #[test]
+        fn max_test() {
+            let num: BigDec<39> = from_be_bytes(&u128::MAX.to_be_bytes()).unwrap();
+            assert_eq!(to_decimals(u128::MAX).as_slice(), num.as_slice());
+        }
+
+        #[test]
+        fn capacity_test() {
+            assert!(from_be_bytes::<3>(&[0x03, 0xE7]).is_some());
+            assert!(from_be_bytes::<3>(&[0x03, 0xE8]).is_none());
+            assert!(from_be_bytes::<0>(&[0]).is_none());
+        }
+    }
+
+    mod to_be_bytes {
+        use crate::{to_be_bytes, to_decimals, to_le_bytes, AsSlice};
+
+        #[test]
+        fn basic_test() {
+            let mut buf = [0; 4];
+            assert_eq!(Some(&[0x01, 0x00][..]), to_be_bytes(&[6, 5, 2], &mut buf));
+        }
+
+        #[test]
+        fn le_test() {
+            let mut buf = [0; 4];
+            assert_eq!(Some(&[0x00, 0x01][..]), to_le_bytes(&[6, 5, 2], &mut buf));
+        }
+
+        #[test]
+        fn zero_test() {
+            let mut buf = [0xFF; 4];
+            assert_eq!(Some(&[0][..]), to_be_bytes(&[0, 0], &mut buf));
+        }
+
+        #[test]
+        fn max_test() {
+            let mut buf = [0; 16];
+            let bytes = to_be_bytes(to_decimals(u128::MAX).as_slice(), &mut buf);
+            assert_eq!(Some(&u128::MAX.to_be_bytes()[..]), bytes);
+        }
+
+        #[test]
+        fn short_buffer_test() {
+            let mut buf = [0; 1];
+            assert_eq!(None, to_be_bytes(&[6, 5, 2], &mut buf));
+            assert_eq!(None, to_le_bytes(&[1], &mut []));
+        }
+
+        #[test]
+        fn invalid_place_test() {
+            let mut buf = [0; 4];
+            assert_eq!(None, to_be_bytes(&[1, 10], &mut buf));
+        }
+    }
+
+    mod write_radix {
+        use crate::{to_decimals, write_radix, AsSlice};
+
+        #[test]
+        fn hex_test() {
+            let mut buf = [0; 32];
+            let num = to_decimals(0xDEAD_BEEF);
+            assert_eq!(Some("deadbeef"), write_radix(num.as_slice(), 16, &mut buf));
+        }
+
+        #[test]
+        fn oct_bin_test() {
+            let mut buf = [0; 32];
+            assert_eq!(Some("777"), write_radix(&[1, 1, 5], 8, &mut buf));
+            assert_eq!(Some("101010"), write_radix(&[2, 4], 2, &mut buf));
+        }
+
+        #[test]
+        fn zero_test() {
+            let mut buf = [0; 1];
+            assert_eq!(Some("0"), write_radix(&[0, 0], 2, &mut buf));
+        }
+
+        #[test]
+        fn max_test() {
+            let mut buf = [0; 128];
+            let num = to_decimals(u128::MAX);
+            assert_eq!(
+                Some(&[b'1'; 128][..]),
+                write_radix(num.as_slice(), 2, &mut buf).map(str::as_bytes)
+            );
+        }
+
+        #[test]
+        fn short_buffer_test() {
+            let mut buf = [0; 2];
+            assert_eq!(None, write_radix(&[6, 5, 2], 16, &mut buf));
+        }
+
+        #[test]
+        fn radix_test() {
+            let mut buf = [0; 2];
+            assert_eq!(None, write_radix(&[1], 37, &mut buf));
+            assert_eq!(None, write_radix(&[1], 1, &mut buf));
+        }
+    }
+
+    mod parse_radix {
+        use crate::{parse_radix, to_decimals, AsSlice, BigDec, ParseError};
+
+        #[test]
+        fn hex_test() {
+            let num: BigDec<10> = parse_radix("0xDead_beef", 16).unwrap();
+            assert_eq!(to_decimals(0xDEAD_BEEF).as_slice(), num.as_slice());
+        }
+
+        #[test]
+        fn oct_bin_test() {
+            let num: BigDec<3> = parse_radix("0o777", 8).unwrap();
+            assert_eq!(&[1, 1, 5], num.as_slice());
+
+            let num: BigDec<3> = parse_radix("0B10_1010", 2).unwrap();
+            assert_eq!(&[2, 4], num.as_slice());
+        }
+
+        #[test]
+        fn no_prefix_test() {
+            let num: BigDec<3> = parse_radix("00ff", 16).unwrap();
+            assert_eq!(&[5, 5, 2], num.as_slice());
+
+            let num: BigDec<3> = parse_radix("0b", 16).unwrap();
+            assert_eq!(&[1, 1], num.as_slice());
+        }
+
+        #[test]
+        fn max_test() {
+            let num: BigDec<39> = parse_radix("ffffffff_ffffffff_ffffffff_ffffffff", 16).unwrap();
+            assert_eq!(to_decimals(u128::MAX).as_slice(), num.as_slice());
+        }
+
+        #[test]
+        fn empty_test() {
+            assert_eq!(Err(ParseError::Empty), parse_radix::<3>("", 16).map(|_| ()));
+            assert_eq!(
+                Err(ParseError::Empty),
+                parse_radix::<3>("0x", 16).map(|_| ())
+            );
+        }
+
+        #[test]
+        fn invalid_char_test() {
+            assert_eq!(
+                Err(ParseError::InvalidChar(1)),
+                parse_radix::<3>("12", 2).map(|_| ())
+            );
+            assert_eq!(
+                Err(ParseError::InvalidChar(2)),
+                parse_radix::<3>("0x_f", 16).map(|_| ())
+            );
+            assert_eq!(
+                Err(ParseError::InvalidChar(1)),
+                parse_radix::<3>("0x1", 8).map(|_| ())
+            );
+        }
+
+        #[test]
+        fn overflow_test() {
+            assert_eq!(
+                Err(ParseError::Overflow),
+                parse_radix::<3>("3e8", 16).map(|_| ())
+            );
+        }
+
+        #[test]
+        fn radix_test() {
+            assert_eq!(
+                Err(ParseError::InvalidRadix),
+                parse_radix::<3>("10", 37).map(|_| ())
+            );
+            assert_eq!(
+                Err(ParseError::InvalidRadix),
+                parse_radix::<3>("0", 0).map(|_| ())
+            );
+        }
+    }
+
+    mod to_radix_places {
+        use crate::{
+            from_radix_places, to_decimals, to_radix_places, AsSlice, Bin32, Bin8, Dec2, Dec4,
+        };
+
+        #[test]
+        fn dec_test() {
+            let mut places = [0; 4];
+            let len = to_radix_places::<Dec2>(&[5, 4, 3, 2, 1], &mut places).unwrap();
+            assert_eq!(&[45, 23, 1], &places[..len]);
+
+            let mut places = [0; 4];
+            let len = to_radix_places::<Dec4>(&[5, 4, 3, 2, 1], &mut places).unwrap();
+            assert_eq!(&[2345, 1], &places[..len]);
+        }
+
+        #[test]
+        fn bin_test() {
+            let num = to_decimals(0x0123_4567_89AB_CDEF);
+
+            let mut places = [0; 8];
+            let len = to_radix_places::<Bin8>(num.as_slice(), &mut places).unwrap();
+            assert_eq!(
+                &[0xEF, 0xCD, 0xAB, 0x89, 0x67, 0x45, 0x23, 0x01],
+                &places[..len]
+            );
+
+            let mut places = [0; 2];
+            let len = to_radix_places::<Bin32>(num.as_slice(), &mut places).unwrap();
+            assert_eq!(&[0x89AB_CDEF, 0x0123_4567], &places[..len]);
+        }
+
+        #[test]
+        fn short_buffer_test() {
+            let mut places = [0; 1];
+            assert_eq!(None, to_radix_places::<Dec2>(&[0, 0, 1], &mut places));
+        }
+
+        #[test]
+        fn round_trip_test() {
+            let num = to_decimals(u128::MAX);
+
+            let mut places = [0; 4];
+            let len = to_radix_places::<Bin32>(num.as_slice(), &mut places).unwrap();
+            assert_eq!(&[u32::MAX; 4], &places[..len]);
+
+            let mut dec = [0; 39];
+            let len = from_radix_places::<Bin32>(&places[..len], &mut dec).unwrap();
+            assert_eq!(num.as_slice(), &dec[..len]);
+        }
+
+        #[test]
+        fn invalid_place_test() {
+            let mut dec = [0; 8];
+            assert_eq!(None, from_radix_places::<Dec2>(&[100], &mut dec));
+        }
+    }
+
+    mod add_assign_radix {
+        use crate::{add_assign_radix, Bin32, Dec4};
+
+        #[test]
+        fn basic_test() {
+            let mut augend = [9999, 9999, 0, 0];
+            let len = add_assign_radix::<Dec4>(&mut augend, 2, &[1]).unwrap();
+
+            assert_eq!(3, len);
+            assert_eq!([0, 0, 1, 0], augend);
+        }
+
+        #[test]
+        fn bin_test() {
+            let mut augend = [u32::MAX, 1, 0];
+            let len = add_assign_radix::<Bin32>(&mut augend, 2, &[u32::MAX, u32::MAX]).unwrap();
+
+            assert_eq!(3, len);
+            assert_eq!([u32::MAX - 1, 1, 1], augend);
+        }
+
+        #[test]
+        fn overflow_test() {
+            let mut augend = [u32::MAX];
+            assert_eq!(None, add_assign_radix::<Bin32>(&mut augend, 1, &[1]));
+        }
+    }
+
+    mod sub_assign_radix {
+        use crate::{sub_assign_radix, Bin32, Dec4};
+
+        #[test]
+        fn basic_test() {
+            let mut minuend = [0, 0, 1];
+            let len = sub_assign_radix::<Dec4>(&mut minuend, 3, &[1]).unwrap();
+
+            assert_eq!(2, len);
+            assert_eq!([9999, 9999, 0], minuend);
+        }
+
+        #[test]
+        fn bin_test() {
+            let mut minuend = [0, 0, 1];
+            let len = sub_assign_radix::<Bin32>(&mut minuend, 3, &[1, 1]).unwrap();
+
+            assert_eq!(2, len);
+            assert_eq!([u32::MAX, u32::MAX - 1, 0], minuend);
+        }
+
+        #[test]
+        fn underflow_test() {
+            let mut minuend = [1, 1];
+            assert_eq!(None, sub_assign_radix::<Bin32>(&mut minuend, 2, &[2, 1]));
+            assert_eq!([1, 1], minuend);
+        }
+    }
+
+    mod mul_into_radix {
+        use crate::{mul_into_radix, Bin32, Dec2};
+
+        #[test]
+        fn basic_test() {
+            let mut prod = [0; 4];
+            let len = mul_into_radix::<Dec2>(&[99, 99], &[99, 99], &mut prod).unwrap();
+
+            // 9999² = 99980001
+            assert_eq!(4, len);
+            assert_eq!([1, 0, 98, 99], prod);
+        }
+
+        #[test]
+        fn bin_test() {
+            let mut prod = [0; 4];
+            let max = [u32::MAX, u32::MAX];
+            let len = mul_into_radix::<Bin32>(&max, &max, &mut prod).unwrap();
+
+            // (2⁶⁴ - 1)² = 2¹²⁸ - 2⁶⁵ + 1
+            assert_eq!(4, len);
+            assert_eq!([1, 0, u32::MAX - 1, u32::MAX], prod);
+        }
+
+        #[test]
+        fn overflow_test() {
+            let mut prod = [0; 3];
+            let max = [u32::MAX, u32::MAX];
+            assert_eq!(None, mul_into_radix::<Bin32>(&max, &max, &mut prod));
+        }
+    }
+
+    mod div_rem_radix {
+        use crate::{div_rem_radix, Bin32, Dec4, Place};
+
+        fn wide(places: &[u32]) -> u128 {
+            let mut num = 0;
+            for ix in (0..places.len()).rev() {
+                num = (num << 32) | places[ix].wide() as u128;
+            }
+
+            num
+        }
+
+        fn places(mut num: u128) -> [u32; 4] {
+            let mut places = [0; 4];
+            for place in places.iter_mut() {
+                *place = num as u32;
+                num >>= 32;
+            }
+
+            places
+        }
+
+        #[test]
+        fn basic_test() {
+            let mut dividend = [4444, 4444];
+            let mut quot = [0; 2];
+
+            let (quot_len, rem_len) =
+                div_rem_radix::<Dec4>(&mut dividend, &[44], &mut quot).unwrap();
+
+            // 44444444 = 44 × 1010101 + 0
+            assert_eq!(&[101, 101], &quot[..quot_len]);
+            assert_eq!(&[0], &dividend[..rem_len]);
+        }
+
+        #[test]
+        fn bin_test() {
+            let cases = [
+                (u128::MAX, 3),
+                (u128::MAX, u64::MAX as u128),
+                (u128::MAX, (1 << 64) + 1),
+                (u128::MAX - 12_345, u128::MAX / 7),
+                (1 << 100, (1 << 32) + 7),
+                (0xDEAD_BEEF_0000_0000_1234_5678, 0xFFFF_FFFF),
+                (5, 7),
+            ];
+
+            for (end, sor) in cases {
+                let mut dividend = places(end);
+                let divisor = places(sor);
+                let mut quot = [0; 4];
+
+                let (quot_len, rem_len) =
+                    div_rem_radix::<Bin32>(&mut dividend, &divisor, &mut quot).unwrap();
+
+                assert_eq!(end / sor, wide(&quot[..quot_len]), "{end} / {sor}");
+                assert_eq!(end % sor, wide(&dividend[..rem_len]), "{end} % {sor}");
+            }
+        }
+
+        #[test]
+        fn zero_divisor_test() {
+            let mut quot = [0; 2];
+            assert_eq!(
+                None,
+                div_rem_radix::<Bin32>(&mut [1, 1], &[0, 0], &mut quot)
+            );
+        }
+
+        #[test]
+        fn short_quotient_test() {
+            let mut quot = [0; 1];
+            assert_eq!(None, div_rem_radix::<Bin32>(&mut [1, 1], &[1], &mut quot));
+        }
+    }
+
+    mod pow_radix {
+        use crate::{pow_radix, Bin32, Dec4};
+
+        #[test]
+        fn basic_test() {
+            let (pow, len) = pow_radix::<Dec4, 3>(&[2], 20).unwrap();
+
+            // 2²⁰ = 1048576
+            assert_eq!(&[8576, 104], &pow[..len]);
+        }
+
+        #[test]
+        fn bin_test() {
+            let (pow, len) = pow_radix::<Bin32, 5>(&[2], 127).unwrap();
+            assert_eq!(&[0, 0, 0, 1 << 31], &pow[..len]);
+
+            let (pow, len) = pow_radix::<Bin32, 5>(&[0, 1], 4).unwrap();
+            assert_eq!(&[0, 0, 0, 0, 1], &pow[..len]);
+        }
+
+        #[test]
+        fn overflow_test() {
+            assert!(pow_radix::<Bin32, 4>(&[2], 128).is_none());
+        }
+    }
+
+    mod to_limbs {
+        use crate::{from_limbs, to_decimals, to_limbs, AsSlice};
+
+        #[test]
+        fn basic_test() {
+            let num = to_decimals(0x0123_4567_89AB_CDEF);
+
+            let mut limbs = [0; 3];
+            let len = to_limbs(num.as_slice(), &mut limbs).unwrap();
+            assert_eq!(&[0x89AB_CDEF, 0x0123_4567], &limbs[..len]);
+
+            let mut dec = [0; 20];
+            let len = from_limbs(&limbs[..len], &mut dec).unwrap();
+            assert_eq!(num.as_slice(), &dec[..len]);
+        }
+
+        #[test]
+        fn zero_test() {
+            let mut limbs = [7; 2];
+            assert_eq!(Some(1), to_limbs(&[0, 0], &mut limbs));
+            assert_eq!(0, limbs[0]);
+
+            let mut dec = [7; 2];
+            assert_eq!(Some(1), from_limbs(&[0, 0], &mut dec));
+            assert_eq!(0, dec[0]);
+        }
+
+        #[test]
+        fn short_buffer_test() {
+            let mut limbs = [0; 1];
+            let num = to_decimals(1 << 32);
+            assert_eq!(None, to_limbs(num.as_slice(), &mut limbs));
+
+            let mut dec = [0; 9];
+            assert_eq!(None, from_limbs(&[1_000_000_000], &mut dec));
+        }
+    }
+
+    mod mul_limbs {
+        use crate::{mul, mul_limbs, pow_cap, to_decimals, AsSlice, BigDec};
+
+        #[test]
+        fn basic_test() {
+            let prod = mul_limbs::<40>(&[5, 5, 2], &[5, 3, 5, 5, 6]).unwrap();
+            assert_eq!(to_decimals(255 * 65_535).as_slice(), prod.as_slice());
+        }
+
+        #[test]
+        fn zero_test() {
+            let big = [9; 1000];
+            let prod = mul_limbs::<2>(&big, &[0]).unwrap();
+            assert_eq!(&[0], prod.as_slice());
+        }
+
+        #[test]
+        fn load_test() {
+            let a: BigDec<400> = pow_cap(&[7], 350);
+            let b: BigDec<400> = pow_cap(&[3], 400);
+
+            let dec = mul::<800>(a.as_slice(), b.as_slice()).unwrap();
+            let bin = mul_limbs::<800>(a.as_slice(), b.as_slice()).unwrap();
+            assert_eq!(dec.as_slice(), bin.as_slice());
+        }
+
+        #[test]
+        fn overflow_test() {
+            assert!(mul_limbs::<3>(&[2, 3], &[5, 4]).is_none());
+            assert!(mul_limbs::<0>(&[1], &[1]).is_none());
+        }
+    }
+
+    mod div_rem_limbs {
+        use crate::{div_rem, div_rem_limbs, pow, to_decimals, AsSlice};
+
+        #[test]
+        fn basic_test() {
+            let (quot, rem) = div_rem_limbs::<5>(&[4, 4, 4, 4], &[4, 4]).unwrap();
+            assert_eq!(&[1, 0, 1], quot.as_slice());
+            assert_eq!(&[0], rem.as_slice());
+        }
+
+        #[test]
+        fn lesser_dividend_test() {
+            let mut divisor = [0; 1000];
+            divisor[999] = 1;
+
+            let (quot, rem) = div_rem_limbs::<2>(&[9, 6], &divisor).unwrap();
+            assert_eq!(&[0], quot.as_slice());
+            assert_eq!(&[9, 6], rem.as_slice());
+        }
+
+        #[test]
+        fn load_test() {
+            let end = pow(&[7], 900);
+            let sor = pow(&[3], 700);
+
+            let (dec_quot, dec_rem) = div_rem::<815>(end.as_slice(), sor.as_slice()).unwrap();
+            let (bin_quot, bin_rem) = div_rem_limbs::<815>(end.as_slice(), sor.as_slice()).unwrap();
+
+            assert_eq!(dec_quot.as_slice(), bin_quot.as_slice());
+            assert_eq!(dec_rem.as_slice(), bin_rem.as_slice());
+        }
+
+        #[test]
+        fn u128_test() {
+            let end = to_decimals(u128::MAX);
+            let sor = to_decimals(18_446_744_073_709_551_557);
+
+            let (quot, rem) = div_rem_limbs::<39>(end.as_slice(), sor.as_slice()).unwrap();
+            let q = u128::MAX / 18_446_744_073_709_551_557;
+            let r = u128::MAX % 18_446_744_073_709_551_557;
+
+            assert_eq!(to_decimals(q).as_slice(), quot.as_slice());
+            assert_eq!(to_decimals(r).as_slice(), rem.as_slice());
+        }
+
+        #[test]
+        fn zero_divisor_test() {
+            assert!(div_rem_limbs::<3>(&[1], &[0, 0]).is_none());
+        }
+
+        #[test]
+        fn capacity_test() {
+            assert!(div_rem_limbs::<2>(&[1, 2, 3], &[1]).is_none());
+        }
+    }
+
+    mod pow_limbs {
+        use crate::{pow, pow_limbs, AsSlice};
+
+        #[test]
+        fn basic_test() {
+            let pow = pow_limbs::<4>(&[2], 10).unwrap();
+            assert_eq!(&[4, 2, 0, 1], pow.as_slice());
+        }
+
+        #[test]
+        fn zero_one_test() {
+            assert_eq!(&[1], pow_limbs::<1>(&[7], 0).unwrap().as_slice());
+            assert_eq!(&[0], pow_limbs::<1>(&[0], 5).unwrap().as_slice());
+            assert_eq!(&[1], pow_limbs::<1>(&[1], u64::MAX).unwrap().as_slice());
+        }
+
+        #[test]
+        fn load_test() {
+            let dec = pow(&[2], 2_700);
+            let bin = pow_limbs::<815>(&[2], 2_700).unwrap();
+            assert_eq!(dec.as_slice(), bin.as_slice());
+        }
+
+        #[test]
+        fn overflow_test() {
+            // 2¹⁰ has 4 places
+            assert!(pow_limbs::<3>(&[2], 10).is_none());
+            assert!(pow_limbs::<815>(&[0, 1], 815).is_none());
+            assert!(pow_limbs::<815>(&[2], u64::MAX).is_none());
+        }
+    }
+
+    mod rem_limbs {
+        use crate::{pow, rem_core, rem_limbs, to_decimals, AsSlice, Dec};
+
+        #[test]
+        fn basic_test() {
+            let mut end = to_decimals(1_000_000_007);
+            let len = rem_limbs(&mut end.0, &[0, 0, 0, 1]).unwrap();
+
+            assert_eq!(&[7], &end.0[..len]);
+            assert!(end.0[len..].iter().all(|&p| p == 0));
+        }
+
+        #[test]
+        fn load_test() {
+            let end = pow(&[7], 900);
+            let sor = pow(&[3], 300);
+
+            let mut dec = end;
+            let dec_len = rem_core::<Dec>(&mut dec.0, sor.as_slice(), None);
+
+            let mut bin = end;
+            let bin_len = rem_limbs(&mut bin.0, sor.as_slice()).unwrap();
+
+            assert_eq!(&dec.0[..dec_len], &bin.0[..bin_len]);
+        }
+
+        #[test]
+        fn capacity_test() {
+            let mut end = [1; 900];
+            assert!(rem_limbs(&mut end, &[3]).is_none());
+            assert!(rem_limbs(&mut [1], &end).is_none());
+        }
+    }
+
+    mod mul_rem_limbs {
+        use crate::{mul_rem_limbs, to_decimals, AsSlice};
+
+        #[test]
+        fn basic_test() {
+            let a = to_decimals(u64::MAX as u128);
+            let m = to_decimals(1_000_000_007);
+            let mut prod = [9; 20];
+
+            let len = mul_rem_limbs(a.as_slice(), a.as_slice(), m.as_slice(), &mut prod).unwrap();
+            let r = (u64::MAX as u128 % 1_000_000_007).pow(2) % 1_000_000_007;
+
+            assert_eq!(to_decimals(r).as_slice(), &prod[..len]);
+            assert!(prod[len..].iter().all(|&p| p == 0));
+        }
+
+        #[test]
+        fn capacity_test() {
+            let mut prod = [0; 4];
+            assert!(mul_rem_limbs(&[1; 900], &[1], &[7], &mut prod).is_none());
+        }
+    }
+
+    // public functions dispatched to limbs, capacities beyond `MAX_PLACES`
+    // stay on decimal places and serve as reference, so do places up to
+    // `u128` range
+    #[cfg(feature = "binary-limbs")]
+    mod binary_limbs {
+        use crate::{
+            div_rem, gcd, mod_inv, mul, pow, pow_cap, pow_mod, rem, rem_big, to_decimals, AsSlice,
+            AsSliceMut, BigDec,
+        };
+
+        #[test]
+        fn mul_test() {
+            let a: BigDec<400> = pow_cap(&[7], 350);
+            let b: BigDec<400> = pow_cap(&[3], 400);
+
+            let bin = mul::<800>(a.as_slice(), b.as_slice()).unwrap();
+            let dec = mul::<900>(a.as_slice(), b.as_slice()).unwrap();
+            assert_eq!(dec.as_slice(), bin.as_slice());
+
+            assert!(mul::<3>(&[9, 9], &[9, 9]).is_none());
+            assert!(mul::<40>(&[9; 20], &[9; 21]).is_none());
+        }
+
+        #[test]
+        fn div_rem_test() {
+            let end = pow(&[7], 900);
+            let sor = pow(&[3], 700);
+
+            let (bin_quot, bin_rem) = div_rem::<815>(end.as_slice(), sor.as_slice()).unwrap();
+            let (dec_quot, dec_rem) = div_rem::<900>(end.as_slice(), sor.as_slice()).unwrap();
+
+            assert_eq!(dec_quot.as_slice(), bin_quot.as_slice());
+            assert_eq!(dec_rem.as_slice(), bin_rem.as_slice());
+
+            assert!(div_rem::<3>(&[1], &[0]).is_none());
+        }
+
+        #[test]
+        fn pow_test() {
+            let bin = pow(&[2], 2_700);
+            let dec = pow_cap::<900>(&[2], 2_700);
+            assert_eq!(dec.as_slice(), bin.as_slice());
+
+            let max = pow_cap::<39>(&[2], 127);
+            assert_eq!(to_decimals(1 << 127).as_slice(), max.as_slice());
+        }
+
+        #[test]
+        fn rem_test() {
+            let mut end = to_decimals(u128::MAX);
+            let sor = to_decimals(18_446_744_073_709_551_557);
+
+            let r = u128::MAX % 18_446_744_073_709_551_557;
+            assert_eq!(Some(r), rem(end.as_slice_mut(), sor.as_slice()));
+
+            let mut end = pow(&[7], 900);
+            let len = rem_big(end.as_slice_mut(), &[7, 0, 0, 0, 0, 0, 0, 0, 0, 1]).unwrap();
+            let r = (0..900).fold(1u128, |r, _| r * 7 % 1_000_000_007);
+            assert_eq!(to_decimals(r).as_slice(), &end.as_slice()[..len]);
+        }
+
+        #[test]
+        fn pow_mod_test() {
+            // 2¹²⁷ - 1 is prime
+            let p = to_decimals(u128::MAX >> 1);
+            let exp = to_decimals((u128::MAX >> 1) - 1);
+
+            // Fermat's little theorem
+            let pow = pow_mod::<80>(&[3], exp.as_slice(), p.as_slice()).unwrap();
+            assert_eq!(&[1], pow.as_slice());
+        }
+
+        #[test]
+        fn gcd_test() {
+            let a = mul::<200>(pow(&[7], 60).as_slice(), pow(&[3], 10).as_slice()).unwrap();
+            let b = mul::<200>(pow(&[7], 20).as_slice(), pow(&[3], 40).as_slice()).unwrap();
+            let g = mul::<200>(pow(&[7], 20).as_slice(), pow(&[3], 10).as_slice()).unwrap();
+
+            let gcd = gcd::<200>(a.as_slice(), b.as_slice()).unwrap();
+            assert_eq!(g.as_slice(), gcd.as_slice());
+        }
+
+        #[test]
+        fn mod_inv_test() {
+            let m = to_decimals(u128::MAX >> 1);
+
+            // 3 × (2¹²⁸ - 1)/3 = 2 × (2¹²⁷ - 1) + 1
+            let inv = mod_inv::<80>(&[3], m.as_slice()).unwrap();
+            assert_eq!(to_decimals(u128::MAX / 3).as_slice(), inv.as_slice());
+        }
+    }
+
+    mod to_decimals_widths {
+        use crate::{
+            to_decimals_i16, to_decimals_i32, to_decimals_i64, to_decimals_i8, to_decimals_isize,
+            to_decimals_u16, to_decimals_u32, to_decimals_u64, to_decimals_u8, to_decimals_usize,
+            AsSlice,
+        };
+
+        #[test]
+        fn unsigned_test() {
+            assert_eq!(&[5, 5, 2], to_decimals_u8(u8::MAX).as_slice());
+            assert_eq!(&[5, 3, 5, 5, 6], to_decimals_u16(u16::MAX).as_slice());
+            assert_eq!(&[0], to_decimals_u32(0).as_slice());
+            assert_eq!(
+                &[5, 1, 6, 1, 5, 5, 9, 0, 7, 3, 7, 0, 4, 4, 7, 6, 4, 4, 8, 1],
+                to_decimals_u64(u64::MAX).as_slice()
+            );
+            assert_eq!(&[1, 0, 1], to_decimals_usize(101).as_slice());
+        }
+
+        #[test]
+        fn signed_test() {
+            let dec = to_decimals_i8(i8::MIN);
+            assert!(dec.is_neg());
+            assert_eq!(&[8, 2, 1], dec.as_slice());
+
+            let dec = to_decimals_i16(i16::MAX);
+            assert!(!dec.is_neg());
+            assert_eq!(&[7, 6, 7, 2, 3], dec.as_slice());
+
+            let dec = to_decimals_i32(-1);
+            assert!(dec.is_neg());
+            assert_eq!(&[1], dec.as_slice());
+
+            let dec = to_decimals_i64(i64::MIN);
+            assert!(dec.is_neg());
+            assert_eq!(
+                &[8, 0, 8, 5, 7, 7, 4, 5, 8, 6, 3, 0, 2, 7, 3, 3, 2, 2, 9],
+                dec.as_slice()
+            );
+
+            let dec = to_decimals_isize(0);
+            assert!(!dec.is_neg());
+            assert_eq!(&[0], dec.as_slice());
+        }
+    }
+
+    mod try_from_decimals_widths {
+        use crate::{
+            to_decimals_i16, to_decimals_i8, try_from_decimals_i16, try_from_decimals_i8,
+            try_from_decimals_u64, try_from_decimals_u8, Error, SignedBigDec,
+        };
+
+        #[test]
+        fn unsigned_test() {
+            assert_eq!(Ok(255), try_from_decimals_u8(&[5, 5, 2]));
+            assert_eq!(Err(Error::Overflow), try_from_decimals_u8(&[6, 5, 2]));
+            assert_eq!(
+                Ok(u64::MAX),
+                try_from_decimals_u64(&[
+                    5, 1, 6, 1, 5, 5, 9, 0, 7, 3, 7, 0, 4, 4, 7, 6, 4, 4, 8, 1
+                ])
+            );
+            assert_eq!(
+                Err(Error::Overflow),
+                try_from_decimals_u64(&[
+                    6, 1, 6, 1, 5, 5, 9, 0, 7, 3, 7, 0, 4, 4, 7, 6, 4, 4, 8, 1
+                ])
+            );
+        }
+
+        #[test]
+        fn unsigned_invalid_test() {
+            assert_eq!(Err(Error::InvalidDigit), try_from_decimals_u8(&[10]));
+            assert_eq!(Err(Error::EmptyInput), try_from_decimals_u8(&[]));
+        }
+
+        #[test]
+        fn signed_bounds_test() {
+            assert_eq!(Ok(i8::MIN), try_from_decimals_i8(&to_decimals_i8(i8::MIN)));
+            assert_eq!(Ok(i8::MAX), try_from_decimals_i8(&to_decimals_i8(i8::MAX)));
+
+            let min: SignedBigDec<3> = SignedBigDec::from_places(true, &[9, 2, 1]).unwrap();
+            assert_eq!(Err(Error::Overflow), try_from_decimals_i8(&min));
+
+            let max: SignedBigDec<3> = SignedBigDec::from_places(false, &[8, 2, 1]).unwrap();
+            assert_eq!(Err(Error::Overflow), try_from_decimals_i8(&max));
+        }
+
+        #[test]
+        fn signed_round_trip_test() {
+            for num in [i16::MIN, -1000, -1, 0, 1, 999, i16::MAX] {
+                assert_eq!(Ok(num), try_from_decimals_i16(&to_decimals_i16(num)));
+            }
+        }
+    }
+
+    mod from_decimals_widths {
+        use crate::{
+            from_decimals_i128, from_decimals_i32, from_decimals_u16, from_decimals_usize,
+            to_decimals_i128, to_decimals_i32, SignedBigDec,
+        };
+
+        #[test]
+        fn basic_test() {
+            assert_eq!(65_535, from_decimals_u16(&[5, 3, 5, 5, 6]));
+            assert_eq!(1024, from_decimals_usize(&[4, 2, 0, 1]));
+            assert_eq!(
+                -2_147_483_648,
+                from_decimals_i32(&to_decimals_i32(i32::MIN))
+            );
+            assert_eq!(i128::MIN, from_decimals_i128(&to_decimals_i128(i128::MIN)));
+        }
+
+        #[test]
+        fn truncation_test() {
+            assert_eq!(0, from_decimals_u16(&[6, 3, 5, 5, 6]));
+
+            let num: SignedBigDec<10> =
+                SignedBigDec::from_places(true, &[9, 4, 6, 3, 8, 4, 7, 4, 1, 2]).unwrap();
+            assert_eq!(i32::MAX, from_decimals_i32(&num));
+        }
+    }
+
+    mod try_from_decimals_i128 {
+        use crate::{to_decimals_i128, try_from_decimals_i128, Error, SignedBigDec};
+
+        #[test]
+        fn basic_test() {
+            for num in [i128::MIN, -1, 0, 1, i128::MAX] {
+                assert_eq!(Ok(num), try_from_decimals_i128(&to_decimals_i128(num)));
+            }
+        }
+
+        #[test]
+        fn overflow_test() {
+            // |i128::MIN| + 1
+            let places = [
+                9, 2, 7, 5, 0, 1, 4, 8, 8, 5, 1, 7, 3, 0, 3, 7, 8, 6, 1, 3, 7, 1, 3, 2, 9, 6, 4, 0,
+                6, 4, 3, 8, 1, 1, 4, 1, 0, 7, 1,
+            ];
+            let num: SignedBigDec<39> = SignedBigDec::from_places(true, &places).unwrap();
+            assert_eq!(Err(Error::Overflow), try_from_decimals_i128(&num));
+        }
+    }
+
+    mod rem {
+        use crate::{rem, to_decimals, AsSlice, AsSliceMut};
+
+        #[test]
+        fn basic_test() {
+            let mut dividend = to_decimals(65000);
+            let divisor = to_decimals(5);
+
+            let rem = rem(dividend.as_slice_mut(), divisor.as_slice());
+            assert_eq!(Some(0), rem);
+
+            // assert_eq!(7, unsafe { LOOP_COUNTER });
+        }
+
+        #[test]
+        fn advanced_test1() {
+            let mut dividend = to_decimals(65535);
+            let divisor = to_decimals(277);
+
+            let rem = rem(dividend.as_slice_mut(), divisor.as_slice());
+            assert_eq!(Some(163), rem);
+
+            // assert_eq!(15, unsafe { LOOP_COUNTER });
+            // 65535 -2× 27700 ⇒ 2 +1
+            // 10135 -3×  2770 ⇒ 3 +1
+            // 1825  -6×   277 ⇒ 6 +1
+            // rem 163 ⇒ Σ 14 +1 for reentry
+        }
+
+        #[test]
+        fn advanced_test2() {
+            let mut dividend = to_decimals(65535);
+            let divisor = to_decimals(27);
+
+            let rem = rem(dividend.as_slice_mut(), divisor.as_slice());
+            assert_eq!(Some(6), rem);
+
+            // assert_eq!(19, unsafe { LOOP_COUNTER });
+            // 65535 -2× 27000 ⇒ 2 +1
+            // 11535 -4×  2700 ⇒ 4 +1
+            // 735   -2×   270 ⇒ 2 +1
+            // 195   -7×    27 ⇒ 7 +1
+            // rem 6 ⇒ Σ 19, no reentry
+        }
+
+        #[test]
+        fn advanced_test3() {
+            let mut dividend = to_decimals(65535);
+            let divisor = to_decimals(69);
+
+            let rem = rem(dividend.as_slice_mut(), divisor.as_slice());
+            assert_eq!(Some(54), rem);
+
+            // assert_eq!(26, unsafe { LOOP_COUNTER });
+            // 65535 -9× 6900 ⇒ 9 +1
+            // 3435  -4×  690 ⇒ 4 +1
+            // 675   -9×   69 ⇒ 9 +1
+            // rem 54 ⇒ Σ 25 +1 for reentry
+        }
+
+        #[test]
+        fn advanced_test4() {
+            let mut dividend = to_decimals(65535);
+            let divisor = to_decimals(65536);
+
+            let rem = rem(dividend.as_slice_mut(), divisor.as_slice());
+            assert_eq!(Some(65535), rem);
+            // assert_eq!(1, unsafe { LOOP_COUNTER });
+        }
+
+        #[test]
+        fn advanced_test5() {
+            let mut dividend = to_decimals(65535);
+            let divisor = to_decimals(65535);
+
+            let rem = rem(dividend.as_slice_mut(), divisor.as_slice());
+            assert_eq!(Some(0), rem);
+            // assert_eq!(2, unsafe { LOOP_COUNTER });
+        }
+
+        #[test]
+        fn advanced_test6() {
+            let mut dividend = to_decimals(60_000);
+            let divisor = to_decimals(6001); // cannot broaden up
+
+            let rem = rem(dividend.as_slice_mut(), divisor.as_slice());
+            assert_eq!(Some(5991), rem);
+            // assert_eq!(11, unsafe { LOOP_COUNTER });
+            // 65535 -9× 6001 ⇒ 9 +1
+            // rem 5991       ⇒ Σ 10 +1 for reentry
+        }
+
+        #[test]
+        fn advanced_test7() {
+            let mut dividend = to_decimals(123);
+            let divisor = to_decimals(1234);
+
+            let rem = rem(dividend.as_slice_mut(), divisor.as_slice());
+            assert_eq!(Some(123), rem);
+            // assert_eq!(0, unsafe { LOOP_COUNTER });
+        }
+
+        #[test]
+        fn advanced_test8() {
+            let mut dividend = to_decimals(65535);
+            let divisor = to_decimals(6553);
+
+            let rem = rem(dividend.as_slice_mut(), divisor.as_slice());
+            assert_eq!(Some(5), rem);
+            // assert_eq!(2, unsafe { LOOP_COUNTER });
+            // 65535 -1× 65530 ⇒ 1 +1
+            // rem 5           ⇒ Σ 2, no reentry
+        }
+
+        #[test]
+        fn advanced_test9() {
+            let mut dividend = to_decimals(65000);
+            let divisor = to_decimals(65);
+
+            let rem = rem(dividend.as_slice_mut(), divisor.as_slice());
+            assert_eq!(Some(0), rem);
+            // assert_eq!(2, unsafe { LOOP_COUNTER });
+            // 65000 -1× 65000 ⇒ 1 +1
+            // rem 0           ⇒ Σ 2, no reentry
+        }
+
+        #[test]
+        fn widening_test() {
+            let mut dividend = to_decimals(1_000_000_007);
+            let divisor = to_decimals(1_000);
+
+            let rem = rem(dividend.as_slice_mut(), divisor.as_slice());
+            assert_eq!(Some(7), rem);
+        }
+
+        #[test]
+        fn zero_divisor_test() {
+            let mut dividend = to_decimals(65_535);
+
+            let rem = rem(dividend.as_slice_mut(), &[0]);
+            assert_eq!(None, rem);
+        }
+
+        #[test]
+        fn zero_divisor_leading_zeros_test() {
+            let mut dividend = to_decimals(65_535);
+
+            let rem = rem(dividend.as_slice_mut(), &[0, 0]);
+            assert_eq!(None, rem);
+        }
+
+        #[test]
+        fn empty_divisor_test() {
+            let mut dividend = to_decimals(65_535);
+
+            let rem = rem(dividend.as_slice_mut(), &[]);
+            assert_eq!(None, rem);
+        }
+
+        #[test]
+        fn divisor_leading_zeros_test() {
+            let mut dividend = to_decimals(65_535);
+
+            let rem = rem(dividend.as_slice_mut(), &[7, 7, 2, 0, 0]);
+            assert_eq!(Some(163), rem);
+        }
+
+        #[test]
+        fn load_test() {
+            let mut dividend = to_decimals(u128::MAX);
+            let divisor = to_decimals(249);
+
+            let rem = rem(dividend.as_slice_mut(), divisor.as_slice());
+            assert_eq!(Some(216), rem);
+        }
+    }
+
+    mod try_rem {
+        use crate::{to_decimals, try_rem, AsSlice, AsSliceMut, Error};
+
+        #[test]
+        fn basic_test() {
+            let mut dividend = to_decimals(65_535);
+            let divisor = to_decimals(277);
+
+            assert_eq!(
+                Ok(163),
+                try_rem(dividend.as_slice_mut(), divisor.as_slice())
+            );
+        }
+
+        #[test]
+        fn overflow_test() {
+            let mut dividend = [0; 41];
+            dividend[40] = 1;
+
+            let mut divisor = [0; 41];
+            divisor[40] = 2;
+
+            assert_eq!(Err(Error::Overflow), try_rem(&mut dividend, &divisor));
+        }
+
+        #[test]
+        fn zero_divisor_test() {
+            let mut dividend = to_decimals(65_535);
+
+            assert_eq!(
+                Err(Error::DivisionByZero),
+                try_rem(dividend.as_slice_mut(), &[0])
+            );
+        }
+
+        #[test]
+        fn empty_test() {
+            let mut dividend = to_decimals(65_535);
+
+            assert_eq!(
+                Err(Error::EmptyInput),
+                try_rem(dividend.as_slice_mut(), &[])
+            );
+        }
+
+        #[test]
+        fn invalid_digit_test() {
+            let divisor = to_decimals(7);
+
+            assert_eq!(
+                Err(Error::InvalidDigit),
+                try_rem(&mut [12], divisor.as_slice())
+            );
+        }
+    }
+
+    mod rem_big {
+        use crate::{pow, rem_big, to_decimals, AsSlice, AsSliceMut};
+
+        #[test]
+        fn basic_test() {
+            let mut dividend = to_decimals(65_535);
+            let divisor = to_decimals(277);
+
+            let len = rem_big(dividend.as_slice_mut(), divisor.as_slice());
+            assert_eq!(Some(3), len);
+            assert_eq!(&[3, 6, 1], &dividend.0[..3]);
+        }
+
+        #[test]
+        fn cleared_places_test() {
+            let mut dividend = [5, 3, 5, 5, 6];
+
+            let len = rem_big(&mut dividend, &[7, 7, 2]);
+            assert_eq!(Some(3), len);
+            assert_eq!([3, 6, 1, 0, 0], dividend);
+        }
+
+        #[test]
+        fn zero_rem_test() {
+            let mut dividend = to_decimals(65_000);
+            let divisor = to_decimals(65);
+
+            let len = rem_big(dividend.as_slice_mut(), divisor.as_slice());
+            assert_eq!(Some(1), len);
+            assert_eq!(0, dividend.0[0]);
+        }
+
+        #[test]
+        fn smaller_dividend_test() {
+            let mut dividend = to_decimals(123);
+            let divisor = to_decimals(1_234);
+
+            let len = rem_big(dividend.as_slice_mut(), divisor.as_slice());
+            assert_eq!(Some(3), len);
+            assert_eq!(&[3, 2, 1], &dividend.0[..3]);
+        }
+
+        #[test]
+        fn big_modulus_test() {
+            // 10⁶⁰ +7 mod 10⁵⁰ = 7
+            let mut dividend = pow(&[0, 1], 60);
+            dividend.0[0] = 7;
+            let divisor = pow(&[0, 1], 50);
+
+            let len = rem_big(dividend.as_slice_mut(), divisor.as_slice());
+            assert_eq!(Some(1), len);
+            assert_eq!(7, dividend.0[0]);
+        }
+
+        #[test]
+        fn big_remainder_test() {
+            // 3×10⁵⁰ +4×10⁴⁵ mod 10⁵⁰ = 4×10⁴⁵
+            let mut dividend = [0; 51];
+            dividend[50] = 3;
+            dividend[45] = 4;
+
+            let divisor = pow(&[0, 1], 50);
+
+            let len = rem_big(&mut dividend, divisor.as_slice());
+            assert_eq!(Some(46), len);
+
+            let mut proof = [0; 46];
+            proof[45] = 4;
+            assert_eq!(proof, dividend[..46]);
+        }
+
+        #[test]
+        fn zero_divisor_test() {
+            let mut dividend = to_decimals(1);
+
+            let len = rem_big(dividend.as_slice_mut(), &[0]);
+            assert_eq!(None, len);
+        }
+    }
+
+    mod try_rem_big {
+        use crate::{try_rem_big, Error};
+
+        #[test]
+        fn basic_test() {
+            let mut dividend = [5, 3, 5, 5, 6];
+
+            assert_eq!(Ok(3), try_rem_big(&mut dividend, &[7, 7, 2]));
+            assert_eq!([3, 6, 1], dividend[..3]);
+        }
+
+        #[test]
+        fn zero_divisor_test() {
+            assert_eq!(Err(Error::DivisionByZero), try_rem_big(&mut [1], &[0, 0]));
+        }
+
+        #[test]
+        fn empty_test() {
+            assert_eq!(Err(Error::EmptyInput), try_rem_big(&mut [], &[1]));
+        }
+    }
+
+    mod div_rem {
+        use crate::{div_rem, to_decimals, AsSlice, BigDec};
+
+        fn div_rem_aux(dividend: u128, divisor: u128) {
+            let end = to_decimals(dividend);
+            let sor = to_decimals(divisor);
+
+            let (quot, rem): (BigDec, BigDec) = div_rem(end.as_slice(), sor.as_slice()).unwrap();
+            assert_eq!(to_decimals(dividend / divisor).as_slice(), quot.as_slice());
+            assert_eq!(to_decimals(dividend % divisor).as_slice(), rem.as_slice());
+        }
+
+        #[test]
+        fn empty_test() {
+            let (quot, rem): (BigDec<4>, BigDec<4>) = div_rem(&[], &[7]).unwrap();
+            assert_eq!((&[0][..], 1), (quot.as_slice(), quot.1));
+            assert_eq!((&[0][..], 1), (rem.as_slice(), rem.1));
+        }
+
+        #[test]
+        fn basic_test() {
+            div_rem_aux(65_000, 5);
+        }
+
+        #[test]
+        fn advanced_test1() {
+            div_rem_aux(65_535, 277);
+        }
+
+        #[test]
+        fn advanced_test2() {
+            div_rem_aux(65_535, 69);
+        }
+
+        #[test]
+        fn advanced_test3() {
+            div_rem_aux(60_000, 6_001);
+        }
+
+        #[test]
+        fn advanced_test4() {
+            div_rem_aux(1_000_000_007, 1_000);
+        }
+
+        #[test]
+        fn equal_test() {
+            div_rem_aux(65_535, 65_535);
+        }
+
+        #[test]
+        fn smaller_dividend_test() {
+            div_rem_aux(123, 1_234);
+        }
+
+        #[test]
+        fn zero_dividend_test() {
+            div_rem_aux(0, 7);
+        }
+
+        #[test]
+        fn load_test() {
+            div_rem_aux(u128::MAX, 249);
+        }
+
+        #[test]
+        fn load_test2() {
+            div_rem_aux(u128::MAX, u64::MAX as u128 + 5);
+        }
+
+        #[test]
+        fn big_test() {
+            let mut dividend = [0; 100];
+            dividend[99] = 1;
+
+            let (quot, rem): (BigDec, BigDec) = div_rem(&dividend, &[0, 0, 1]).unwrap();
+
+            assert_eq!(98, quot.1);
+            assert_eq!(&dividend[2..], quot.as_slice());
+            assert_eq!(&[0], rem.as_slice());
+        }
+
+        #[test]
+        fn leading_zeros_test() {
+            let (quot, rem): (BigDec, BigDec) = div_rem(&[7, 1, 0, 0], &[5, 0]).unwrap();
+            assert_eq!(&[3], quot.as_slice());
+            assert_eq!(&[2], rem.as_slice());
+        }
+
+        #[test]
+        fn zero_divisor_test() {
+            let res: Option<(BigDec, BigDec)> = div_rem(&[1], &[0]);
+            assert!(res.is_none());
+        }
 
-    unsafe {
-        *mcand = *sum;
-        *sum = swap.as_mut().unwrap();
+        #[test]
+        fn capacity_test() {
+            let res: Option<(BigDec<2>, BigDec<2>)> = div_rem(&[0, 0, 1], &[1]);
+            assert!(res.is_none());
+        }
     }
-}
 
-fn pow_linear(base: &[u8], pow: u8) -> decimalsMax {
-    let mut aux1 = [0; MAX_PLACES];
-    if pow == 0 {
-        aux1[0] = 1;
-        return (aux1, 1);
-    }
+    mod try_div_rem {
+        use crate::{try_div_rem, AsSlice, BigDec, Error};
 
-    let base_len = base.len();
-    for ix in 0..base_len {
-        aux1[ix] = base[ix]
-    }
+        #[test]
+        fn basic_test() {
+            let (quot, rem): (BigDec, BigDec) = try_div_rem(&[7, 1], &[5]).unwrap();
 
-    if pow == 1 {
-        return (aux1, base_len);
-    }
+            assert_eq!(&[3], quot.as_slice());
+            assert_eq!(&[2], rem.as_slice());
+        }
 
-    let mut aux2 = [0; MAX_PLACES];
+        #[test]
+        fn zero_divisor_test() {
+            let res: Result<(BigDec, BigDec), Error> = try_div_rem(&[7, 1], &[0]);
+            assert_eq!(Err(Error::DivisionByZero), res.map(|_| ()));
+        }
 
-    let mut mcand = &mut aux1;
-    let mut sum = &mut aux2;
+        #[test]
+        fn overflow_test() {
+            let res: Result<(BigDec<1>, BigDec<1>), Error> = try_div_rem(&[7, 1], &[5]);
+            assert_eq!(Err(Error::Overflow), res.map(|_| ()));
+        }
 
-    let mut mcand_len = base_len;
-    let mut sum_len = 0;
+        #[test]
+        fn invalid_digit_test() {
+            let res: Result<(BigDec, BigDec), Error> = try_div_rem(&[7, 1], &[10]);
+            assert_eq!(Err(Error::InvalidDigit), res.map(|_| ()));
+        }
+    }
 
-    let mut limit = (pow - 1) as usize;
-    loop {
-        for base_off in 0..base_len {
-            sum_len = muladd(&mcand[0..mcand_len], base[base_off], sum, base_off);
+    mod len_trim {
+        use crate::len_trim;
+
+        #[test]
+        fn basic_test() {
+            assert_eq!(2, len_trim::<u8>(&[1, 2, 0, 0]));
         }
 
-        mcand_len = sum_len;
-        limit -= 1;
-        if limit == 0 {
-            mcand = sum;
-            break;
+        #[test]
+        fn zero_test() {
+            assert_eq!(1, len_trim::<u8>(&[0, 0, 0]));
         }
 
-        clear_swap(&mut mcand, mcand_len, &mut sum);
+        #[test]
+        fn no_trim_test() {
+            assert_eq!(3, len_trim::<u8>(&[0, 0, 1]));
+        }
     }
 
-    (*mcand, mcand_len)
-}
+    mod rem_crux {
 
-fn muladd(mcand: &[u8], mpler: u8, sum: &mut [u8], base_off: usize) -> usize {
-    let mut sum_max_ix = 0;
+        use crate::{
+            decimalsU128, from_decimals, rem_crux, to_decimals, AsSlice, Dec, MAX_DEC_PLACES,
+        };
 
-    let mut ix = 0;
-    let mcand_len = mcand.len();
+        fn rem_crux_aux(dividend: &mut decimalsU128, divisor: &decimalsU128) -> u128 {
+            let end = &mut dividend.0;
+            let end_len = rem_crux::<Dec>(end, &divisor.0, dividend.1, divisor.1, &mut 0);
+            from_decimals(&end[..end_len])
+        }
 
-    loop {
-        let prod = mpler * mcand[ix];
+        #[test]
+        fn basic_test() {
+            let mut dividend = to_decimals(4444);
+            let divisor = to_decimals(44);
 
-        let max_wr_ix = sumadd(prod, sum, base_off + ix);
+            let mut takes = 0;
 
-        if max_wr_ix > sum_max_ix {
-            sum_max_ix = max_wr_ix
-        };
+            let end_len = rem_crux::<Dec>(&mut dividend.0, &divisor.0, 4, 2, &mut takes);
+            assert_eq!(1, end_len);
+            assert_eq!(101, takes);
+            let mut proof = [0; MAX_DEC_PLACES];
+            proof[2..=3].fill_with(|| 9);
+            assert_eq!(&proof, &dividend.0);
+        }
 
-        ix += 1;
+        #[test]
+        fn advanced_test() {
+            let mut dividend = to_decimals(171);
+            let divisor = to_decimals(22);
 
-        if ix == mcand_len {
-            break;
+            let rem = rem_crux_aux(&mut dividend, &divisor);
+            assert_eq!(17, rem);
         }
-    }
 
-    sum_max_ix + 1
-}
+        #[test]
+        fn takeover_test() {
+            let mut dividend = to_decimals(909);
+            let divisor = to_decimals(9);
 
-fn sumadd(mut addend: u8, sum: &mut [u8], mut off: usize) -> usize {
-    let mut takeover = 0;
+            let rem = rem_crux_aux(&mut dividend, &divisor);
+            assert_eq!(0, rem);
+        }
 
-    loop {
-        let augend = sum[off];
+        #[test]
+        fn longer_divisor_test1() {
+            let mut dividend = to_decimals(69);
+            let divisor = to_decimals(244);
+
+            let end_len = rem_crux::<Dec>(&mut dividend.0, &divisor.0, 2, 3, &mut 0);
+            assert_eq!(2, end_len);
+            assert_eq!(&[5, 2], dividend.as_slice());
+            // to get correct result, `69`, `rem_crux` have to be updated
+        }
 
-        sum[off] = ones(augend + addend, &mut takeover);
+        #[test]
+        fn longer_divisor_test2() {
+            let mut dividend = to_decimals(69);
+            let divisor = to_decimals(270);
 
-        if takeover == 0 {
-            break;
-        } else {
-            addend = 0;
-            off += 1;
+            let rem = rem_crux_aux(&mut dividend, &divisor);
+            assert_eq!(69, rem);
         }
-    }
 
-    off
-}
+        #[test]
+        fn longer_divisor_test3() {
+            let mut dividend = to_decimals(69);
+            let divisor = to_decimals(269);
 
-fn ones(num: u8, takeover_ref: &mut u8) -> u8 {
-    let mut takeover_val = *takeover_ref;
-    let total = num + takeover_val;
+            let rem = rem_crux_aux(&mut dividend, &divisor);
+            assert_eq!(0, rem);
+        }
+    }
 
-    takeover_val = total / 10;
-    *takeover_ref = takeover_val;
+    mod add {
+        use crate::{add, to_decimals, AsSlice, BigDec};
 
-    total - takeover_val * 10
-}
+        #[test]
+        fn basic_test() {
+            let sum: BigDec = add(&[4], &[5]).unwrap();
+            assert_eq!(&[9], sum.as_slice());
+        }
 
-#[cfg(test)]
-mod tests_of_units {
+        #[test]
+        fn takeover_test() {
+            let augend = to_decimals(9_999);
+            let addend = to_decimals(1);
 
-    mod herons_sqrt {
-        use crate::herons_sqrt;
+            let sum: BigDec = add(augend.as_slice(), addend.as_slice()).unwrap();
+            assert_eq!(&[0, 0, 0, 0, 1], sum.as_slice());
+        }
 
         #[test]
-        fn basic_test() {
-            assert_eq!(4, herons_sqrt(16));
+        fn shorter_augend_test() {
+            let augend = to_decimals(7);
+            let addend = to_decimals(65_535);
+
+            let sum: BigDec = add(augend.as_slice(), addend.as_slice()).unwrap();
+            assert_eq!(&[2, 4, 5, 5, 6], sum.as_slice());
         }
 
         #[test]
-        fn test_17() {
-            assert_eq!(4, herons_sqrt(17));
+        fn leading_zeros_test() {
+            let sum: BigDec = add(&[1, 0, 0], &[2, 0]).unwrap();
+            assert_eq!(&[3], sum.as_slice());
         }
 
         #[test]
-        fn test_24() {
-            assert_eq!(4, herons_sqrt(24));
+        fn zero_test() {
+            let sum: BigDec = add(&[0], &[0]).unwrap();
+            assert_eq!(&[0], sum.as_slice());
         }
 
         #[test]
-        fn test_25() {
-            assert_eq!(5, herons_sqrt(25));
+        fn empty_test() {
+            let sum: BigDec<4> = add(&[], &[]).unwrap();
+            assert_eq!(&[0], sum.as_slice());
+            assert_eq!(1, sum.1);
         }
 
         #[test]
         fn load_test() {
-            assert_eq!(255, herons_sqrt(65025));
+            let augend = to_decimals(u128::MAX);
+
+            let sum: BigDec = add(augend.as_slice(), augend.as_slice()).unwrap();
+            let proof = [
+                0, 1, 9, 2, 2, 4, 6, 3, 5, 3, 6, 8, 4, 1, 2, 9, 4, 7, 6, 2, 9, 6, 2, 9, 6, 7, 8, 1,
+                4, 8, 3, 3, 7, 4, 6, 5, 0, 8, 6,
+            ];
+
+            assert_eq!(proof, sum.as_slice());
         }
 
         #[test]
-        fn one_test() {
-            assert_eq!(1, herons_sqrt(1));
+        fn overflow_test() {
+            let sum: Option<BigDec<2>> = add(&[9, 9], &[1]);
+            assert!(sum.is_none());
         }
 
         #[test]
-        fn zero_test() {
-            assert_eq!(0, herons_sqrt(0));
+        fn capacity_test() {
+            let sum: Option<BigDec<2>> = add(&[9, 8], &[1]);
+            assert_eq!(&[0, 9], sum.unwrap().as_slice());
         }
     }
 
-    mod prime_ck {
-        use crate::prime_ck;
+    mod try_add {
+        use crate::{try_add, AsSlice, BigDec, Error};
 
         #[test]
         fn basic_test() {
-            assert!(prime_ck(3));
+            let sum: BigDec = try_add(&[9], &[1]).unwrap();
+            assert_eq!(&[0, 1], sum.as_slice());
         }
 
         #[test]
-        fn two_test() {
-            assert!(prime_ck(2));
+        fn overflow_test() {
+            let sum: Result<BigDec<1>, Error> = try_add(&[9], &[1]);
+            assert_eq!(Err(Error::Overflow), sum.map(|_| ()));
         }
 
         #[test]
-        fn even_test() {
-            assert_eq!(false, prime_ck(256));
+        fn empty_test() {
+            let sum: Result<BigDec, Error> = try_add(&[], &[1]);
+            assert_eq!(Err(Error::EmptyInput), sum.map(|_| ()));
         }
 
         #[test]
-        fn test_65521() {
-            assert!(prime_ck(65521));
+        fn invalid_digit_test() {
+            let sum: Result<BigDec, Error> = try_add(&[1], &[1, 15]);
+            assert_eq!(Err(Error::InvalidDigit), sum.map(|_| ()));
         }
+    }
+
+    mod add_assign {
+        use crate::add_assign;
 
         #[test]
-        fn test_49() {
-            assert_eq!(false, prime_ck(49));
+        fn basic_test() {
+            let mut augend = [5, 2, 0, 0];
+
+            let len = add_assign(&mut augend, 2, &[7, 9]);
+            assert_eq!(Some(3), len);
+            assert_eq!([2, 2, 1, 0], augend);
         }
 
         #[test]
-        fn one_test() {
-            assert_eq!(false, prime_ck(1));
+        fn free_places_test() {
+            let mut augend = [1, 7, 7, 7];
+
+            let len = add_assign(&mut augend, 1, &[1]);
+            assert_eq!(Some(1), len);
+            assert_eq!(2, augend[0]);
         }
 
         #[test]
-        fn zero_test() {
-            assert_eq!(false, prime_ck(0));
+        fn overflow_test() {
+            let mut augend = [9, 9, 9];
+
+            let len = add_assign(&mut augend, 3, &[1]);
+            assert_eq!(None, len);
+        }
+
+        #[test]
+        fn longer_addend_test() {
+            let mut augend = [1, 0];
+
+            let len = add_assign(&mut augend, 1, &[1, 0, 0, 0]);
+            assert_eq!(Some(1), len);
+            assert_eq!([2, 0], augend);
         }
     }
 
-    mod to_decimals {
-        use crate::{to_decimals, AsSlice, MAX_DEC_PLACES};
+    mod sub {
+        use crate::{sub, to_decimals, AsSlice, BigDec};
 
         #[test]
         fn basic_test() {
-            let decimals = to_decimals(1);
-            assert_eq!(1, decimals.1);
-            let mut proof = [0; MAX_DEC_PLACES];
-            proof[0] = 1;
+            let diff: BigDec = sub(&[9], &[5]).unwrap();
+            assert_eq!(&[4], diff.as_slice());
+        }
 
-            assert_eq!(&proof, &decimals.0);
+        #[test]
+        fn empty_test() {
+            let diff: BigDec<4> = sub(&[], &[]).unwrap();
+            assert_eq!(&[0], diff.as_slice());
+            assert_eq!(1, diff.1);
+
+            let diff: BigDec<4> = sub(&[], &[0]).unwrap();
+            assert_eq!(&[0], diff.as_slice());
         }
 
         #[test]
-        fn zero_test() {
-            let decimals = to_decimals(0);
-            assert_eq!(1, decimals.1);
-            assert_eq!([0; MAX_DEC_PLACES], decimals.0);
+        fn takeover_test() {
+            let minuend = to_decimals(10_000);
+            let subtrahend = to_decimals(1);
+
+            let diff: BigDec = sub(minuend.as_slice(), subtrahend.as_slice()).unwrap();
+            assert_eq!(&[9, 9, 9, 9], diff.as_slice());
         }
 
         #[test]
-        fn test_65535() {
-            let decimals = to_decimals(65535);
-            assert_eq!(5, decimals.1);
-            assert_eq!([5, 3, 5, 5, 6], decimals.as_slice());
+        fn equal_test() {
+            let minuend = to_decimals(65_535);
+
+            let diff: BigDec = sub(minuend.as_slice(), minuend.as_slice()).unwrap();
+            assert_eq!(&[0], diff.as_slice());
         }
-    }
 
-    mod from_decimals {
-        use crate::from_decimals;
+        #[test]
+        fn leading_zeros_test() {
+            let diff: BigDec = sub(&[5, 1, 0, 0], &[4, 1, 0]).unwrap();
+            assert_eq!(&[1], diff.as_slice());
+        }
 
         #[test]
-        fn basic_test() {
-            assert_eq!(1, from_decimals(&[1]));
+        fn underflow_test() {
+            let diff: Option<BigDec> = sub(&[9, 9], &[0, 0, 1]);
+            assert!(diff.is_none());
         }
 
         #[test]
-        fn zero_test() {
-            assert_eq!(0, from_decimals(&[0]));
+        fn underflow_same_len_test() {
+            let diff: Option<BigDec> = sub(&[9, 8], &[0, 9]);
+            assert!(diff.is_none());
         }
 
         #[test]
-        fn zero_place_test() {
-            assert_eq!(101, from_decimals(&[1, 0, 1]));
+        fn capacity_test() {
+            let diff: Option<BigDec<2>> = sub(&[0, 0, 1], &[1]);
+            assert!(diff.is_none());
         }
 
         #[test]
-        fn test_65535() {
-            assert_eq!(u16::MAX as u128, from_decimals(&[5, 3, 5, 5, 6]));
+        fn load_test() {
+            let minuend = to_decimals(u128::MAX);
+            let subtrahend = to_decimals(u64::MAX as u128);
+            let proof = to_decimals(u128::MAX - u64::MAX as u128);
+
+            let diff: BigDec = sub(minuend.as_slice(), subtrahend.as_slice()).unwrap();
+            assert_eq!(proof.as_slice(), diff.as_slice());
         }
     }
 
-    mod rem {
-        use crate::{rem, to_decimals, AsSlice, AsSliceMut, LOOP_COUNTER};
+    mod try_sub {
+        use crate::{try_sub, AsSlice, BigDec, Error};
 
         #[test]
         fn basic_test() {
-            let mut dividend = to_decimals(65000);
-            let divisor = to_decimals(5);
-
-            let rem = rem(dividend.as_slice_mut(), divisor.as_slice());
-            assert_eq!(0, rem);
-
-            // assert_eq!(7, unsafe { LOOP_COUNTER });
+            let diff: BigDec = try_sub(&[0, 1], &[1]).unwrap();
+            assert_eq!(&[9], diff.as_slice());
         }
 
         #[test]
-        fn advanced_test1() {
-            let mut dividend = to_decimals(65535);
-            let divisor = to_decimals(277);
-
-            let rem = rem(dividend.as_slice_mut(), divisor.as_slice());
-            assert_eq!(163, rem);
-
-            // assert_eq!(15, unsafe { LOOP_COUNTER });
-            // 65535 -2× 27700 ⇒ 2 +1
-            // 10135 -3×  2770 ⇒ 3 +1
-            // 1825  -6×   277 ⇒ 6 +1
-            // rem 163 ⇒ Σ 14 +1 for reentry
+        fn underflow_test() {
+            let diff: Result<BigDec, Error> = try_sub(&[1], &[0, 1]);
+            assert_eq!(Err(Error::Underflow), diff.map(|_| ()));
         }
 
         #[test]
-        fn advanced_test2() {
-            let mut dividend = to_decimals(65535);
-            let divisor = to_decimals(27);
-
-            let rem = rem(dividend.as_slice_mut(), divisor.as_slice());
-            assert_eq!(6, rem);
-
-            // assert_eq!(19, unsafe { LOOP_COUNTER });
-            // 65535 -2× 27000 ⇒ 2 +1
-            // 11535 -4×  2700 ⇒ 4 +1
-            // 735   -2×   270 ⇒ 2 +1
-            // 195   -7×    27 ⇒ 7 +1
-            // rem 6 ⇒ Σ 19, no reentry
+        fn overflow_test() {
+            let diff: Result<BigDec<1>, Error> = try_sub(&[0, 1], &[0, 1]);
+            assert_eq!(Err(Error::Overflow), diff.map(|_| ()));
         }
 
         #[test]
-        fn advanced_test3() {
-            let mut dividend = to_decimals(65535);
-            let divisor = to_decimals(69);
+        fn empty_test() {
+            let diff: Result<BigDec, Error> = try_sub(&[1], &[]);
+            assert_eq!(Err(Error::EmptyInput), diff.map(|_| ()));
+        }
+    }
 
-            let rem = rem(dividend.as_slice_mut(), divisor.as_slice());
-            assert_eq!(54, rem);
+    mod sub_assign {
+        use crate::sub_assign;
 
-            // assert_eq!(26, unsafe { LOOP_COUNTER });
-            // 65535 -9× 6900 ⇒ 9 +1
-            // 3435  -4×  690 ⇒ 4 +1
-            // 675   -9×   69 ⇒ 9 +1
-            // rem 54 ⇒ Σ 25 +1 for reentry
+        #[test]
+        fn basic_test() {
+            let mut minuend = [0, 0, 1, 0];
+
+            let len = sub_assign(&mut minuend, 3, &[1]);
+            assert_eq!(Some(2), len);
+            assert_eq!([9, 9, 0, 0], minuend);
         }
 
         #[test]
-        fn advanced_test4() {
-            let mut dividend = to_decimals(65535);
-            let divisor = to_decimals(65536);
+        fn underflow_test() {
+            let mut minuend = [1, 2, 3];
 
-            let rem = rem(dividend.as_slice_mut(), divisor.as_slice());
-            assert_eq!(65535, rem);
-            // assert_eq!(1, unsafe { LOOP_COUNTER });
+            let len = sub_assign(&mut minuend, 2, &[2, 2]);
+            assert_eq!(None, len);
+            assert_eq!([1, 2, 3], minuend);
         }
+    }
+
+    mod cmp {
+        use crate::cmp;
+        use core::cmp::Ordering;
 
         #[test]
-        fn advanced_test5() {
-            let mut dividend = to_decimals(65535);
-            let divisor = to_decimals(65535);
+        fn len_test() {
+            assert_eq!(Ordering::Greater, cmp::<u8>(&[0, 1], &[9]));
+        }
 
-            let rem = rem(dividend.as_slice_mut(), divisor.as_slice());
-            assert_eq!(0, rem);
-            // assert_eq!(2, unsafe { LOOP_COUNTER });
+        #[test]
+        fn place_test() {
+            assert_eq!(Ordering::Less, cmp::<u8>(&[9, 1, 4], &[0, 2, 4]));
         }
 
         #[test]
-        fn advanced_test6() {
-            let mut dividend = to_decimals(60_000);
-            let divisor = to_decimals(6001); // cannot broaden up
+        fn equal_test() {
+            assert_eq!(Ordering::Equal, cmp::<u8>(&[3, 2, 1], &[3, 2, 1]));
+        }
+    }
 
-            let rem = rem(dividend.as_slice_mut(), divisor.as_slice());
-            assert_eq!(5991, rem);
-            // assert_eq!(11, unsafe { LOOP_COUNTER });
-            // 65535 -9× 6001 ⇒ 9 +1
-            // rem 5991       ⇒ Σ 10 +1 for reentry
+    mod mul {
+        use crate::{mul, to_decimals, AsSlice, BigDec};
+
+        #[test]
+        fn basic_test() {
+            let prod: BigDec = mul(&[3], &[3]).unwrap();
+            assert_eq!(&[9], prod.as_slice());
         }
 
         #[test]
-        fn advanced_test7() {
-            let mut dividend = to_decimals(123);
-            let divisor = to_decimals(1234);
+        fn advanced_test() {
+            let mcand = to_decimals(65_535);
+            let mpler = to_decimals(277);
+            let proof = to_decimals(65_535 * 277);
 
-            let rem = rem(dividend.as_slice_mut(), divisor.as_slice());
-            assert_eq!(123, rem);
-            // assert_eq!(0, unsafe { LOOP_COUNTER });
+            let prod: BigDec = mul(mcand.as_slice(), mpler.as_slice()).unwrap();
+            assert_eq!(proof.as_slice(), prod.as_slice());
         }
 
         #[test]
-        fn advanced_test8() {
-            let mut dividend = to_decimals(65535);
-            let divisor = to_decimals(6553);
+        fn zero_place_test() {
+            let mcand = to_decimals(1_001);
+            let mpler = to_decimals(10_100);
+            let proof = to_decimals(1_001 * 10_100);
 
-            let rem = rem(dividend.as_slice_mut(), divisor.as_slice());
-            assert_eq!(5, rem);
-            // assert_eq!(2, unsafe { LOOP_COUNTER });
-            // 65535 -1× 65530 ⇒ 1 +1
-            // rem 5           ⇒ Σ 2, no reentry
+            let prod: BigDec = mul(mcand.as_slice(), mpler.as_slice()).unwrap();
+            assert_eq!(proof.as_slice(), prod.as_slice());
         }
 
         #[test]
-        fn advanced_test9() {
-            let mut dividend = to_decimals(65000);
-            let divisor = to_decimals(65);
+        fn zero_test() {
+            let mcand = to_decimals(65_535);
 
-            let rem = rem(dividend.as_slice_mut(), divisor.as_slice());
-            assert_eq!(0, rem);
-            // assert_eq!(2, unsafe { LOOP_COUNTER });
-            // 65000 -1× 65000 ⇒ 1 +1
-            // rem 0           ⇒ Σ 2, no reentry
+            let prod: BigDec = mul(mcand.as_slice(), &[0, 0]).unwrap();
+            assert_eq!(&[0], prod.as_slice());
+        }
+
+        #[test]
+        fn leading_zeros_test() {
+            let prod: BigDec<2> = mul(&[2, 0, 0, 0], &[3, 1, 0]).unwrap();
+            assert_eq!(&[6, 2], prod.as_slice());
         }
 
         #[test]
         fn load_test() {
-            let mut dividend = to_decimals(u128::MAX);
-            let divisor = to_decimals(249);
+            let mcand = to_decimals(u64::MAX as u128);
+            let proof = to_decimals((u64::MAX as u128) * (u64::MAX as u128));
 
-            let rem = rem(dividend.as_slice_mut(), divisor.as_slice());
-            assert_eq!(216, rem);
+            let prod: BigDec = mul(mcand.as_slice(), mcand.as_slice()).unwrap();
+            assert_eq!(proof.as_slice(), prod.as_slice());
         }
-    }
 
-    mod rem_crux {
+        #[test]
+        fn capacity_test() {
+            let prod: Option<BigDec<2>> = mul(&[0, 1], &[9]);
+            assert_eq!(&[0, 9], prod.unwrap().as_slice());
+        }
 
-        use crate::{decimalsU128, from_decimals, rem_crux, to_decimals, AsSlice, MAX_DEC_PLACES};
+        #[test]
+        fn overflow_test() {
+            let prod: Option<BigDec<2>> = mul(&[0, 1], &[0, 1]);
+            assert!(prod.is_none());
+        }
 
-        fn rem_crux_aux(dividend: &mut decimalsU128, divisor: &decimalsU128) -> u128 {
-            let end = &mut dividend.0;
-            let end_len = rem_crux(end, &divisor.0, dividend.1, divisor.1);
-            from_decimals(&end[..end_len])
+        #[test]
+        fn takeover_overflow_test() {
+            let prod: Option<BigDec<2>> = mul(&[0, 2], &[5]);
+            assert!(prod.is_none());
         }
+    }
+
+    mod try_mul {
+        use crate::{try_mul, AsSlice, BigDec, Error};
 
         #[test]
         fn basic_test() {
-            let mut dividend = to_decimals(4444);
-            let divisor = to_decimals(44);
-
-            let end_len = rem_crux(&mut dividend.0, &divisor.0, 4, 2);
-            assert_eq!(1, end_len);
-            let mut proof = [0; MAX_DEC_PLACES];
-            proof[2..=3].fill_with(|| 9);
-            assert_eq!(&proof, &dividend.0);
+            let prod: BigDec = try_mul(&[5], &[5]).unwrap();
+            assert_eq!(&[5, 2], prod.as_slice());
         }
 
         #[test]
-        fn advanced_test() {
-            let mut dividend = to_decimals(171);
-            let divisor = to_decimals(22);
+        fn overflow_test() {
+            let prod: Result<BigDec<1>, Error> = try_mul(&[5], &[5]);
+            assert_eq!(Err(Error::Overflow), prod.map(|_| ()));
+        }
 
-            let rem = rem_crux_aux(&mut dividend, &divisor);
-            assert_eq!(17, rem);
+        #[test]
+        fn invalid_digit_test() {
+            let prod: Result<BigDec, Error> = try_mul(&[255], &[5]);
+            assert_eq!(Err(Error::InvalidDigit), prod.map(|_| ()));
         }
+    }
+
+    mod mul_into {
+        use crate::mul_into;
 
         #[test]
-        fn takeover_test() {
-            let mut dividend = to_decimals(909);
-            let divisor = to_decimals(9);
+        fn basic_test() {
+            let mut prod = [7; 5];
 
-            let rem = rem_crux_aux(&mut dividend, &divisor);
-            assert_eq!(0, rem);
+            let len = mul_into(&[2, 1], &[2, 1], &mut prod);
+            assert_eq!(Some(3), len);
+            assert_eq!([4, 4, 1], prod[..3]);
         }
 
         #[test]
-        fn longer_divisor_test1() {
-            let mut dividend = to_decimals(69);
-            let divisor = to_decimals(244);
+        fn overflow_test() {
+            let mut prod = [0; 3];
 
-            let end_len = rem_crux(&mut dividend.0, &divisor.0, 2, 3);
-            assert_eq!(2, end_len);
-            assert_eq!(&[5, 2], dividend.as_slice());
-            // to get correct result, `69`, `rem_crux` have to be updated
+            let len = mul_into(&[9, 9], &[9, 9], &mut prod);
+            assert_eq!(None, len);
         }
 
         #[test]
-        fn longer_divisor_test2() {
-            let mut dividend = to_decimals(69);
-            let divisor = to_decimals(270);
+        fn no_capacity_test() {
+            let len = mul_into(&[1], &[1], &mut []);
+            assert_eq!(None, len);
+        }
+    }
 
-            let rem = rem_crux_aux(&mut dividend, &divisor);
-            assert_eq!(69, rem);
+    mod is_zero {
+        use crate::is_zero;
+
+        #[test]
+        fn basic_test() {
+            assert!(is_zero::<u8>(&[0]));
         }
 
         #[test]
-        fn longer_divisor_test3() {
-            let mut dividend = to_decimals(69);
-            let divisor = to_decimals(269);
+        fn leading_zeros_test() {
+            assert!(is_zero::<u8>(&[0, 0, 0]));
+        }
 
-            let rem = rem_crux_aux(&mut dividend, &divisor);
-            assert_eq!(0, rem);
+        #[test]
+        fn nonzero_test() {
+            assert!(!is_zero::<u8>(&[0, 1]));
         }
     }
 
     mod pow {
 
-        use crate::{pow, to_decimals, AsSlice};
+        use crate::{pow, pow_cap, to_decimals, AsSlice, BigDec};
 
         #[test]
         fn basic_test() {
@@ -870,6 +6902,19 @@ mod tests_of_units {
             assert_eq!(proof, pow.as_slice());
         }
 
+        #[test]
+        fn big_exp_test() {
+            let proof = [
+                6, 7, 3, 7, 9, 3, 3, 8, 1, 6, 0, 7, 6, 3, 3, 3, 6, 7, 9, 9, 2, 1, 8, 3, 4, 5, 3, 9,
+                4, 4, 0, 4, 1, 6, 3, 6, 0, 5, 2, 6, 3, 9, 5, 6, 6, 3, 9, 3, 8, 6, 4, 1, 5, 0, 1, 6,
+                1, 8, 7, 3, 9, 0, 4, 8, 8, 6, 5, 4, 4, 8, 6, 2, 6, 8, 0, 6, 8, 4, 4, 3, 3, 6, 7, 9,
+                5, 3, 0, 7, 3, 0, 2,
+            ];
+
+            let pow = pow(&[2], 300);
+            assert_eq!(proof, pow.as_slice());
+        }
+
         #[test]
         fn zero_power_test() {
             let pow = pow(&[0], 0);
@@ -878,6 +6923,7 @@ mod tests_of_units {
         }
 
         #[test]
+        #[allow(clippy::needless_borrow)]
         fn one_power_test() {
             let decimals = to_decimals(3398);
             let decimals = &decimals.0[..decimals.1];
@@ -902,17 +6948,271 @@ mod tests_of_units {
             assert_eq!(1, pow.1);
             assert_eq!(&[1], pow.as_slice());
         }
+
+        #[test]
+        fn capacity_test() {
+            let pow: BigDec<3> = pow_cap(&[2], 9);
+
+            assert_eq!(3, pow.0.len());
+            assert_eq!(&[2, 1, 5], pow.as_slice());
+        }
+
+        #[test]
+        #[should_panic(expected = "power exceeds capacity")]
+        fn overflow_test() {
+            let _: BigDec<3> = pow_cap(&[2], 10);
+        }
+    }
+
+    mod try_pow {
+        use crate::{to_decimals, try_pow, AsSlice, BigDec, Error};
+
+        #[test]
+        fn basic_test() {
+            let pow: BigDec = try_pow(&[2], 10).unwrap();
+            assert_eq!(&[4, 2, 0, 1], pow.as_slice());
+        }
+
+        #[test]
+        fn overflow_test() {
+            let decimals = to_decimals(1559);
+
+            let pow: Result<BigDec, Error> = try_pow(decimals.as_slice(), 255);
+            assert!(pow.is_ok());
+
+            let decimals = to_decimals(1600);
+
+            let pow: Result<BigDec, Error> = try_pow(decimals.as_slice(), 255);
+            assert_eq!(Err(Error::Overflow), pow.map(|_| ()));
+        }
+
+        #[test]
+        fn capacity_test() {
+            let pow: Result<BigDec<3>, Error> = try_pow(&[2], 9);
+            assert!(pow.is_ok());
+
+            let pow: Result<BigDec<3>, Error> = try_pow(&[2], 10);
+            assert_eq!(Err(Error::Overflow), pow.map(|_| ()));
+        }
+
+        #[test]
+        fn base_overflow_test() {
+            let pow: Result<BigDec<1>, Error> = try_pow(&[0, 1], 0);
+            assert_eq!(Err(Error::Overflow), pow.map(|_| ()));
+        }
+
+        #[test]
+        fn fail_fast_test() {
+            let pow: Result<BigDec, Error> = try_pow(&[0, 1], u64::MAX);
+            assert_eq!(Err(Error::Overflow), pow.map(|_| ()));
+        }
+
+        #[test]
+        fn min_len_test() {
+            // 10⁸¹⁴ has 815 places
+            let pow: Result<BigDec, Error> = try_pow(&[0, 1], 814);
+            assert_eq!(815, pow.unwrap().1);
+
+            let pow: Result<BigDec, Error> = try_pow(&[0, 1], 815);
+            assert_eq!(Err(Error::Overflow), pow.map(|_| ()));
+        }
+
+        #[test]
+        fn small_base_overflow_test() {
+            let pow: Result<BigDec, Error> = try_pow(&[2], u64::MAX);
+            assert_eq!(Err(Error::Overflow), pow.map(|_| ()));
+        }
+
+        #[test]
+        fn one_base_test() {
+            let pow: BigDec = try_pow(&[1], u64::MAX).unwrap();
+            assert_eq!(&[1], pow.as_slice());
+        }
+
+        #[test]
+        fn leading_zeros_test() {
+            let pow: BigDec<2> = try_pow(&[3, 0, 0], 2).unwrap();
+            assert_eq!(&[9], pow.as_slice());
+        }
+
+        #[test]
+        fn empty_test() {
+            let pow: Result<BigDec, Error> = try_pow(&[], 2);
+            assert_eq!(Err(Error::EmptyInput), pow.map(|_| ()));
+        }
+    }
+
+    mod try_pow_big_exp {
+        use crate::{to_decimals, try_pow_big_exp, AsSlice, BigDec, Error};
+
+        #[test]
+        fn basic_test() {
+            let pow: BigDec = try_pow_big_exp(&[2], &[0, 1]).unwrap();
+            assert_eq!(&[4, 2, 0, 1], pow.as_slice());
+        }
+
+        #[test]
+        fn u64_max_test() {
+            let exp = to_decimals(u64::MAX as u128);
+
+            let pow: BigDec = try_pow_big_exp(&[1], exp.as_slice()).unwrap();
+            assert_eq!(&[1], pow.as_slice());
+        }
+
+        #[test]
+        fn beyond_u64_test() {
+            let exp = to_decimals(u64::MAX as u128 + 1);
+
+            let pow: BigDec = try_pow_big_exp(&[1], exp.as_slice()).unwrap();
+            assert_eq!(&[1], pow.as_slice());
+
+            let pow: BigDec = try_pow_big_exp(&[0, 0], exp.as_slice()).unwrap();
+            assert_eq!(&[0], pow.as_slice());
+
+            let pow: Result<BigDec, Error> = try_pow_big_exp(&[2], exp.as_slice());
+            assert_eq!(Err(Error::Overflow), pow.map(|_| ()));
+        }
+
+        #[test]
+        fn beyond_u128_test() {
+            let exp = [9; 50];
+
+            let pow: BigDec = try_pow_big_exp(&[1], &exp).unwrap();
+            assert_eq!(&[1], pow.as_slice());
+
+            let pow: Result<BigDec, Error> = try_pow_big_exp(&[2], &exp);
+            assert_eq!(Err(Error::Overflow), pow.map(|_| ()));
+        }
+
+        #[test]
+        fn invalid_digit_test() {
+            let pow: Result<BigDec, Error> = try_pow_big_exp(&[2], &[10]);
+            assert_eq!(Err(Error::InvalidDigit), pow.map(|_| ()));
+        }
+    }
+
+    mod pow_mod {
+        use crate::{pow, pow_mod, to_decimals, AsSlice, BigDec};
+
+        fn pow_mod_aux(base: u128, exp: u128, modulus: u128) -> u128 {
+            let base = to_decimals(base);
+            let exp = to_decimals(exp);
+            let modulus = to_decimals(modulus);
+
+            let pow: BigDec = pow_mod(base.as_slice(), exp.as_slice(), modulus.as_slice()).unwrap();
+            crate::from_decimals(pow.as_slice())
+        }
+
+        #[test]
+        fn basic_test() {
+            assert_eq!(445, pow_mod_aux(4, 13, 497));
+        }
+
+        #[test]
+        fn zero_exp_test() {
+            assert_eq!(1, pow_mod_aux(65_535, 0, 7));
+        }
+
+        #[test]
+        fn one_modulus_test() {
+            assert_eq!(0, pow_mod_aux(65_535, 0, 1));
+        }
+
+        #[test]
+        fn zero_base_test() {
+            assert_eq!(0, pow_mod_aux(0, 65_535, 7));
+        }
+
+        #[test]
+        fn fermat_test() {
+            // aᵖ⁻¹ ≡ 1 (mod p)
+            assert_eq!(1, pow_mod_aux(65_535, 1_000_000_006, 1_000_000_007));
+        }
+
+        #[test]
+        fn big_exp_test() {
+            // 2¹⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰ mod 1000000007
+            assert_eq!(
+                855_473_248,
+                pow_mod_aux(2, 100_000_000_000_000_000_000, 1_000_000_007)
+            );
+        }
+
+        #[test]
+        fn big_base_test() {
+            // (10⁶⁰ +1)³ mod 10⁶⁰ = 1
+            let mut base = pow(&[0, 1], 60);
+            base.0[0] = 1;
+            let modulus = pow(&[0, 1], 60);
+
+            let pow: BigDec = pow_mod(base.as_slice(), &[3], modulus.as_slice()).unwrap();
+            assert_eq!(&[1], pow.as_slice());
+        }
+
+        #[test]
+        fn big_modulus_test() {
+            // 3¹⁰⁰ mod 10⁴⁵
+            let modulus = pow(&[0, 1], 45);
+            let full = pow(&[3], 100);
+
+            let pow: BigDec = pow_mod(&[3], &[0, 0, 1], modulus.as_slice()).unwrap();
+            assert_eq!(&full.as_slice()[..pow.1], pow.as_slice());
+            assert_eq!(45, pow.1);
+        }
+
+        #[test]
+        fn zero_modulus_test() {
+            let pow: Option<BigDec> = pow_mod(&[2], &[2], &[0, 0]);
+            assert!(pow.is_none());
+        }
+
+        #[test]
+        fn capacity_test() {
+            let pow: Option<BigDec<3>> = pow_mod(&[2], &[2], &[7, 9]);
+            assert!(pow.is_none());
+
+            let pow: Option<BigDec<4>> = pow_mod(&[2], &[2], &[7, 9]);
+            assert_eq!(&[4], pow.unwrap().as_slice());
+        }
+    }
+
+    mod halve {
+        use crate::halve;
+
+        #[test]
+        fn basic_test() {
+            let mut num = [8];
+
+            assert_eq!(0, halve(&mut num));
+            assert_eq!([4], num);
+        }
+
+        #[test]
+        fn odd_test() {
+            let mut num = [1, 2, 3];
+
+            assert_eq!(1, halve(&mut num));
+            assert_eq!([0, 6, 1], num);
+        }
+
+        #[test]
+        fn leading_place_test() {
+            let mut num = [1, 1];
+
+            assert_eq!(1, halve(&mut num));
+            assert_eq!([5, 0], num);
+        }
     }
 
     mod muladd {
-        use crate::muladd;
+        use crate::{muladd, Dec};
 
         #[test]
         fn basic_test() {
             let mcand = [3, 2, 1, 0, 0];
             let mut sum = [0; 5];
 
-            let len = muladd(&mcand[0..3], 3, &mut sum, 0);
+            let len = muladd::<Dec>(&mcand[0..3], 3, &mut sum, 0);
 
             assert_eq!([9, 6, 3, 0, 0], sum);
             assert_eq!(3, len);
@@ -923,7 +7223,7 @@ mod tests_of_units {
             let mcand = [1, 1, 1, 0, 0];
             let mut sum = [1, 1, 1, 0, 0];
 
-            let len = muladd(&mcand[0..3], 3, &mut sum, 1);
+            let len = muladd::<Dec>(&mcand[0..3], 3, &mut sum, 1);
             assert_eq!([1, 4, 4, 3, 0], sum);
             assert_eq!(4, len);
         }
@@ -933,7 +7233,7 @@ mod tests_of_units {
             let mcand = [1, 1, 1, 0, 0, 0];
             let mut sum = [9, 9, 9, 0, 0, 0];
 
-            let len = muladd(&mcand[0..3], 9, &mut sum, 1);
+            let len = muladd::<Dec>(&mcand[0..3], 9, &mut sum, 1);
 
             assert_eq!([9, 8, 9, 0, 1, 0], sum);
             assert_eq!(5, len);
@@ -941,13 +7241,13 @@ mod tests_of_units {
     }
 
     mod sumadd {
-        use crate::sumadd;
+        use crate::{sumadd, Dec};
 
         #[test]
         fn basic_test() {
             const OFF: usize = 0;
             let mut sum = [5; 1];
-            let max_wr_ix = sumadd(4, &mut sum, OFF);
+            let max_wr_ix = sumadd::<Dec>(4, &mut sum, OFF);
 
             assert_eq!(OFF, max_wr_ix);
             assert_eq!([9], sum);
@@ -958,7 +7258,7 @@ mod tests_of_units {
             const OFF: usize = 1;
             let mut sum = [0, 5];
 
-            let max_wr_ix = sumadd(4, &mut sum, OFF);
+            let max_wr_ix = sumadd::<Dec>(4, &mut sum, OFF);
 
             assert_eq!(OFF, max_wr_ix);
             assert_eq!([0, 9], sum);
@@ -967,7 +7267,7 @@ mod tests_of_units {
         #[test]
         fn takeover_test() {
             let mut sum = [9, 4, 1];
-            _ = sumadd(1, &mut sum, 0);
+            _ = sumadd::<Dec>(1, &mut sum, 0);
 
             assert_eq!([0, 5, 1], sum);
         }
@@ -975,22 +7275,31 @@ mod tests_of_units {
         #[test]
         fn wite_index_test() {
             let mut sum = [9, 9, 9, 9, 0, 0];
-            let off = sumadd(1, &mut sum, 1);
+            let off = sumadd::<Dec>(1, &mut sum, 1);
 
             assert_eq!(4, off);
             assert_eq!([9, 0, 0, 0, 1, 0], sum);
         }
+
+        #[test]
+        fn overflow_test() {
+            let mut sum = [9, 9];
+            let off = sumadd::<Dec>(1, &mut sum, 0);
+
+            assert_eq!(2, off);
+            assert_eq!([0, 0], sum);
+        }
     }
 
     mod ones {
-        use crate::ones;
+        use crate::{ones, Dec};
 
         #[test]
         fn basic_test() {
             let num = 9;
             let mut takeover = 0;
 
-            assert_eq!(9, ones(num, &mut takeover));
+            assert_eq!(9, ones::<Dec>(num, &mut takeover));
             assert_eq!(0, takeover);
         }
 
@@ -999,7 +7308,7 @@ mod tests_of_units {
             let num = 9;
             let mut takeover = 3;
 
-            assert_eq!(2, ones(num, &mut takeover));
+            assert_eq!(2, ones::<Dec>(num, &mut takeover));
             assert_eq!(1, takeover);
         }
 
@@ -1008,7 +7317,7 @@ mod tests_of_units {
             let num = 246;
             let mut takeover = 9;
 
-            assert_eq!(5, ones(num, &mut takeover));
+            assert_eq!(5, ones::<Dec>(num, &mut takeover));
             assert_eq!(25, takeover);
         }
     }